@@ -0,0 +1,305 @@
+use crate::adapters::http::HttpClient;
+use crate::error::{ScannerError, ScannerResult};
+use crate::{
+    core::model::{Company, Compensation, EmploymentType, Job, RemoteType},
+    domain::job_source::JobSource,
+};
+use async_trait::async_trait;
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+const API_BASE: &str = "https://api.ashbyhq.com/posting-api/job-board";
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AshbySecondaryLocation {
+    pub location: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[allow(non_snake_case)]
+pub struct AshbyPostalAddress {
+    pub addressCountry: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[allow(non_snake_case)]
+pub struct AshbyAddress {
+    pub postalAddress: Option<AshbyPostalAddress>,
+}
+
+/// One component of a compensation tier, e.g. the salary or the equity range.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[allow(non_snake_case)]
+pub struct AshbyCompensationComponent {
+    pub compensationType: String,
+    /// Pay period such as `1 YEAR` or `1 HOUR`.
+    pub interval: Option<String>,
+    pub currencyCode: Option<String>,
+    pub minValue: Option<f64>,
+    pub maxValue: Option<f64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[allow(non_snake_case)]
+pub struct AshbyCompensation {
+    pub compensationTierSummary: Option<String>,
+    #[serde(default)]
+    pub summaryComponents: Vec<AshbyCompensationComponent>,
+}
+
+impl AshbyCompensation {
+    fn into_compensation(self) -> Compensation {
+        let salary = self
+            .summaryComponents
+            .into_iter()
+            .find(|c| c.compensationType.eq_ignore_ascii_case("salary"));
+        Compensation {
+            min: salary.as_ref().and_then(|s| s.minValue),
+            max: salary.as_ref().and_then(|s| s.maxValue),
+            currency: salary.as_ref().and_then(|s| s.currencyCode.clone()),
+            interval: salary
+                .and_then(|s| s.interval)
+                .map(|i| i.trim_start_matches("1 ").to_string()),
+            summary: self.compensationTierSummary,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[allow(non_snake_case)]
+pub struct AshbyJob {
+    pub id: String,
+    pub title: String,
+    pub department: Option<String>,
+    pub team: Option<String>,
+    pub employmentType: Option<String>,
+    pub location: Option<String>,
+    #[serde(default)]
+    pub secondaryLocations: Vec<AshbySecondaryLocation>,
+    pub publishedAt: Option<String>,
+    #[serde(default = "listed_by_default")]
+    pub isListed: bool,
+    #[serde(default)]
+    pub isRemote: bool,
+    pub workplaceType: Option<String>,
+    pub address: Option<AshbyAddress>,
+    pub jobUrl: String,
+    pub descriptionHtml: Option<String>,
+    pub descriptionPlain: Option<String>,
+    pub compensation: Option<AshbyCompensation>,
+}
+
+fn listed_by_default() -> bool {
+    true
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AshbyJobBoard {
+    pub jobs: Vec<AshbyJob>,
+}
+
+impl AshbyJob {
+    pub fn into_job(self, company_name: &str) -> Job {
+        let location = self
+            .location
+            .into_iter()
+            .chain(self.secondaryLocations.into_iter().map(|l| l.location))
+            .collect::<Vec<_>>()
+            .join("; ");
+        let remote_type = self
+            .workplaceType
+            .as_deref()
+            .and_then(RemoteType::from_label)
+            .or(self.isRemote.then_some(RemoteType::Remote));
+        Job {
+            id: self.id,
+            title: self.title,
+            location,
+            description: self.descriptionHtml.or(self.descriptionPlain),
+            external_path: self.jobUrl,
+            company_name: company_name.to_string(),
+            posted_at: self
+                .publishedAt
+                .and_then(|d| DateTime::parse_from_rfc3339(&d).ok())
+                .map(|d| d.date_naive()),
+            employment_type: self
+                .employmentType
+                .as_deref()
+                .and_then(EmploymentType::from_label),
+            remote_type,
+            country: self
+                .address
+                .and_then(|a| a.postalAddress)
+                .and_then(|p| p.addressCountry),
+            department: self.team.or(self.department),
+            compensation: self.compensation.map(AshbyCompensation::into_compensation),
+            ..Default::default()
+        }
+    }
+}
+
+/// Extracts the job board name from `jobs.ashbyhq.com/{org}` or the posting API URL.
+pub fn ashby_board_name(company_url: &str) -> ScannerResult<String> {
+    let invalid =
+        |reason: &str| ScannerError::SourceUrlError(company_url.to_string(), reason.to_string());
+
+    let parsed_url = Url::parse(company_url)?;
+    let domain = parsed_url.domain().ok_or_else(|| invalid("missing host"))?;
+    if domain != "jobs.ashbyhq.com" && domain != "api.ashbyhq.com" {
+        return Err(invalid("host is not jobs.ashbyhq.com or api.ashbyhq.com"));
+    }
+    let path_segments: Vec<&str> = parsed_url
+        .path_segments()
+        .map(|segments| segments.filter(|s| !s.is_empty()).collect())
+        .unwrap_or_default();
+    match path_segments.as_slice() {
+        ["posting-api", "job-board", board, ..] => Ok(board.to_string()),
+        ["posting-api", ..] => Err(invalid(
+            "API path must look like /posting-api/job-board/{board}",
+        )),
+        [board, ..] => Ok(board.to_string()),
+        [] => Err(invalid("missing job board name in path")),
+    }
+}
+
+/// Reads a company's public Ashby job board, compensation included.
+pub struct AshbyClient {
+    http: HttpClient,
+}
+
+impl Default for AshbyClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AshbyClient {
+    pub fn new() -> Self {
+        AshbyClient {
+            http: HttpClient::default(),
+        }
+    }
+
+    pub fn with_http_client(mut self, http: HttpClient) -> Self {
+        self.http = http;
+        self
+    }
+
+    async fn fetch_board(&self, company: &Company) -> ScannerResult<AshbyJobBoard> {
+        let board = ashby_board_name(&company.url)?;
+        let url = format!("{API_BASE}/{board}?includeCompensation=true");
+        let resp = self.http.send(self.http.get(&url)).await?;
+        Ok(resp.json::<AshbyJobBoard>().await?)
+    }
+}
+
+#[async_trait]
+impl JobSource for AshbyClient {
+    async fn get_job_list(&self, company: &Company) -> ScannerResult<Vec<Job>> {
+        let board = self.fetch_board(company).await?;
+        Ok(board
+            .jobs
+            .into_iter()
+            .filter(|job| job.isListed)
+            .map(|job| job.into_job(&company.name))
+            .collect())
+    }
+
+    /// The board already carries descriptions; the board is only refetched when one is missing.
+    async fn get_job_details(&self, job: &Job, company: &Company) -> ScannerResult<Job> {
+        if job.description.is_some() {
+            return Ok(job.clone());
+        }
+        let board = self.fetch_board(company).await?;
+        let description = board
+            .jobs
+            .into_iter()
+            .find(|j| j.id == job.id)
+            .and_then(|j| j.descriptionHtml.or(j.descriptionPlain));
+        Ok(Job {
+            description,
+            ..job.clone()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_parse_ashby_board_name() {
+        for url in [
+            "https://jobs.ashbyhq.com/acme",
+            "https://jobs.ashbyhq.com/acme/0c2b1a3e-5d4f-4b7a-9e8d-1f2a3b4c5d6e",
+            "https://api.ashbyhq.com/posting-api/job-board/acme?includeCompensation=true",
+        ] {
+            assert_eq!(ashby_board_name(url).unwrap(), "acme", "{url}");
+        }
+        assert!(ashby_board_name("https://acme.ashbyhq.com/").is_err());
+        assert!(ashby_board_name("https://jobs.ashbyhq.com/").is_err());
+    }
+
+    #[test]
+    fn test_ashby_job_maps_compensation() {
+        let json = r#"
+            {
+                "apiVersion": "1",
+                "jobs": [
+                    {
+                        "id": "0c2b1a3e-5d4f-4b7a-9e8d-1f2a3b4c5d6e",
+                        "title": "Product Engineer",
+                        "department": "Engineering",
+                        "team": "Growth",
+                        "employmentType": "FullTime",
+                        "location": "New York",
+                        "secondaryLocations": [{ "location": "Remote - US" }],
+                        "publishedAt": "2024-05-01T16:21:55.393+00:00",
+                        "isListed": true,
+                        "isRemote": true,
+                        "address": { "postalAddress": { "addressCountry": "United States" } },
+                        "jobUrl": "https://jobs.ashbyhq.com/acme/0c2b1a3e-5d4f-4b7a-9e8d-1f2a3b4c5d6e",
+                        "applyUrl": "https://jobs.ashbyhq.com/acme/0c2b1a3e-5d4f-4b7a-9e8d-1f2a3b4c5d6e/application",
+                        "descriptionHtml": "<p>Ship fast</p>",
+                        "descriptionPlain": "Ship fast",
+                        "compensation": {
+                            "compensationTierSummary": "$81K – $87K • 0.5% – 1.75%",
+                            "summaryComponents": [
+                                { "compensationType": "EquityPercentage", "interval": "NONE", "minValue": 0.5, "maxValue": 1.75 },
+                                { "compensationType": "Salary", "interval": "1 YEAR", "currencyCode": "USD", "minValue": 81000, "maxValue": 87000 }
+                            ]
+                        }
+                    },
+                    {
+                        "id": "hidden",
+                        "title": "Unlisted role",
+                        "isListed": false,
+                        "jobUrl": "https://jobs.ashbyhq.com/acme/hidden"
+                    }
+                ]
+            }
+        "#;
+        let board = serde_json::from_str::<AshbyJobBoard>(json).unwrap();
+        assert!(!board.jobs[1].isListed);
+        let job = board.jobs.into_iter().next().unwrap().into_job("Acme");
+
+        assert_eq!(job.location, "New York; Remote - US");
+        assert_eq!(job.department.as_deref(), Some("Growth"));
+        assert_eq!(job.employment_type, Some(EmploymentType::FullTime));
+        assert_eq!(job.remote_type, Some(RemoteType::Remote));
+        assert_eq!(job.country.as_deref(), Some("United States"));
+        assert_eq!(job.posted_at, NaiveDate::from_ymd_opt(2024, 5, 1));
+        assert_eq!(
+            job.compensation,
+            Some(Compensation {
+                min: Some(81000.0),
+                max: Some(87000.0),
+                currency: Some("USD".to_string()),
+                interval: Some("YEAR".to_string()),
+                summary: Some("$81K – $87K • 0.5% – 1.75%".to_string()),
+            })
+        );
+    }
+}
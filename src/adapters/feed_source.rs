@@ -0,0 +1,171 @@
+use crate::adapters::http::HttpClient;
+use crate::error::ScannerResult;
+use crate::{
+    core::model::{Company, Job},
+    domain::job_source::JobSource,
+};
+use async_trait::async_trait;
+use chrono::DateTime;
+use roxmltree::Node;
+use std::collections::HashSet;
+
+/// Text of the first child element with one of `names`, matched on the local name so
+/// `content:encoded` and `dc:date` are found whatever prefix the feed binds.
+fn child_text(node: Node, names: &[&str]) -> Option<String> {
+    names.iter().find_map(|name| {
+        node.children()
+            .find(|c| c.is_element() && c.tag_name().name() == *name)
+            .and_then(|c| c.text())
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty())
+    })
+}
+
+/// Atom entries carry several `<link>`s; the `alternate` one (the default relation) points
+/// at the posting.
+fn atom_link(entry: Node) -> Option<String> {
+    entry
+        .children()
+        .filter(|c| c.is_element() && c.tag_name().name() == "link")
+        .find(|l| l.attribute("rel").is_none_or(|rel| rel == "alternate"))
+        .and_then(|l| l.attribute("href"))
+        .map(str::to_string)
+}
+
+fn item_into_job(item: Node, company_name: &str) -> Option<Job> {
+    let atom = item.tag_name().name() == "entry";
+    let link = if atom {
+        atom_link(item)
+    } else {
+        child_text(item, &["link"])
+    };
+    let id = child_text(item, &["guid", "id"]).or(link.clone())?;
+    let posted_at = child_text(item, &["pubDate", "published", "updated", "date"])
+        .and_then(|d| {
+            DateTime::parse_from_rfc2822(&d)
+                .or_else(|_| DateTime::parse_from_rfc3339(&d))
+                .ok()
+        })
+        .map(|d| d.date_naive());
+    Some(Job {
+        id,
+        title: child_text(item, &["title"]).unwrap_or_default(),
+        location: child_text(item, &["location", "region"]).unwrap_or_default(),
+        description: child_text(item, &["encoded", "content", "description", "summary"]),
+        external_path: link.unwrap_or_default(),
+        company_name: company_name.to_string(),
+        posted_at,
+        department: child_text(item, &["category"]),
+        ..Default::default()
+    })
+}
+
+/// Parses an RSS 2.0 or Atom document into jobs, one per `<item>` or `<entry>`.
+pub fn parse_feed(xml: &str, company_name: &str) -> ScannerResult<Vec<Job>> {
+    let document = roxmltree::Document::parse(xml)?;
+    let mut seen = HashSet::new();
+    Ok(document
+        .descendants()
+        .filter(|n| n.is_element() && matches!(n.tag_name().name(), "item" | "entry"))
+        .filter_map(|item| item_into_job(item, company_name))
+        .filter(|job| seen.insert(job.id.clone()))
+        .collect())
+}
+
+/// Reads job openings published as an RSS or Atom feed. The item GUID (Atom `id`) becomes
+/// the job id, its link the external path and its content the description.
+pub struct FeedJobSource {
+    http: HttpClient,
+}
+
+impl Default for FeedJobSource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FeedJobSource {
+    pub fn new() -> Self {
+        FeedJobSource {
+            http: HttpClient::default(),
+        }
+    }
+
+    pub fn with_http_client(mut self, http: HttpClient) -> Self {
+        self.http = http;
+        self
+    }
+}
+
+#[async_trait]
+impl JobSource for FeedJobSource {
+    async fn get_job_list(&self, company: &Company) -> ScannerResult<Vec<Job>> {
+        let resp = self.http.send(self.http.get(&company.url)).await?;
+        parse_feed(&resp.text().await?, &company.name)
+    }
+
+    /// A feed item is all there is to a posting, so the job is returned as listed.
+    async fn get_job_details(&self, job: &Job, _company: &Company) -> ScannerResult<Job> {
+        Ok(job.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_parse_rss_feed() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <rss version="2.0" xmlns:content="http://purl.org/rss/1.0/modules/content/">
+              <channel>
+                <title>Acme Jobs</title>
+                <item>
+                  <title>Site Reliability Engineer</title>
+                  <link>https://acme.com/jobs/sre</link>
+                  <guid isPermaLink="false">acme-sre-42</guid>
+                  <pubDate>Wed, 01 May 2024 09:00:00 +0000</pubDate>
+                  <category>Infrastructure</category>
+                  <description>Short teaser</description>
+                  <content:encoded><![CDATA[<p>Keep things up.</p>]]></content:encoded>
+                </item>
+                <item>
+                  <title>Duplicate</title>
+                  <guid>acme-sre-42</guid>
+                </item>
+              </channel>
+            </rss>"#;
+        let jobs = parse_feed(xml, "Acme").unwrap();
+        assert_eq!(jobs.len(), 1);
+        let job = &jobs[0];
+        assert_eq!(job.id, "acme-sre-42");
+        assert_eq!(job.title, "Site Reliability Engineer");
+        assert_eq!(job.external_path, "https://acme.com/jobs/sre");
+        assert_eq!(job.description.as_deref(), Some("<p>Keep things up.</p>"));
+        assert_eq!(job.department.as_deref(), Some("Infrastructure"));
+        assert_eq!(job.posted_at, NaiveDate::from_ymd_opt(2024, 5, 1));
+    }
+
+    #[test]
+    fn test_parse_atom_feed() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+            <feed xmlns="http://www.w3.org/2005/Atom">
+              <title>Acme Jobs</title>
+              <entry>
+                <title>Data Engineer</title>
+                <link rel="self" href="https://acme.com/feed/entries/7"/>
+                <link href="https://acme.com/jobs/data-engineer"/>
+                <id>tag:acme.com,2024:jobs/7</id>
+                <updated>2024-05-01T09:00:00Z</updated>
+                <summary>Pipelines.</summary>
+              </entry>
+            </feed>"#;
+        let jobs = parse_feed(xml, "Acme").unwrap();
+        assert_eq!(jobs[0].id, "tag:acme.com,2024:jobs/7");
+        assert_eq!(jobs[0].external_path, "https://acme.com/jobs/data-engineer");
+        assert_eq!(jobs[0].description.as_deref(), Some("Pipelines."));
+        assert_eq!(jobs[0].posted_at, NaiveDate::from_ymd_opt(2024, 5, 1));
+        assert!(parse_feed("<rss><channel>", "Acme").is_err());
+    }
+}
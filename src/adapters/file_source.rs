@@ -0,0 +1,149 @@
+use crate::error::{ScannerError, ScannerResult};
+use crate::{
+    core::model::{Company, Job},
+    domain::job_source::JobSource,
+};
+use async_trait::async_trait;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use url::Url;
+
+/// Parses one snapshot file: `.ndjson`/`.jsonl` hold one `Job` per line, `.json` a single
+/// `Job` or an array of them.
+pub fn parse_jobs(content: &str, path: &Path) -> ScannerResult<Vec<Job>> {
+    match path.extension().and_then(|e| e.to_str()) {
+        Some("ndjson" | "jsonl") => content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| Ok(serde_json::from_str::<Job>(line)?))
+            .collect(),
+        _ => {
+            let value: serde_json::Value = serde_json::from_str(content)?;
+            Ok(if value.is_array() {
+                serde_json::from_value(value)?
+            } else {
+                vec![serde_json::from_value(value)?]
+            })
+        }
+    }
+}
+
+/// Writes `jobs` as NDJSON, the format `FileJobSource` replays.
+pub fn write_snapshot(path: &Path, jobs: &[Job]) -> ScannerResult<()> {
+    let mut file = std::io::BufWriter::new(std::fs::File::create(path)?);
+    for job in jobs {
+        serde_json::to_writer(&mut file, job)?;
+        file.write_all(b"\n")?;
+    }
+    file.flush()?;
+    Ok(())
+}
+
+/// `Company::url` is a `file://` URL or a plain path, to a directory or a single file.
+fn snapshot_path(company_url: &str) -> ScannerResult<PathBuf> {
+    match Url::parse(company_url) {
+        Ok(url) if url.scheme() == "file" => url.to_file_path().map_err(|_| {
+            ScannerError::SourceUrlError(company_url.to_string(), "not a local path".to_string())
+        }),
+        _ => Ok(PathBuf::from(company_url)),
+    }
+}
+
+/// Replays jobs captured to disk (see `write_snapshot`), so the matching pipeline can be
+/// rerun, or compared across models, without touching the network. Files are read in name
+/// order and jobs keep their recorded descriptions.
+#[derive(Default)]
+pub struct FileJobSource;
+
+impl FileJobSource {
+    pub fn new() -> Self {
+        FileJobSource
+    }
+}
+
+#[async_trait]
+impl JobSource for FileJobSource {
+    async fn get_job_list(&self, company: &Company) -> ScannerResult<Vec<Job>> {
+        let path = snapshot_path(&company.url)?;
+        let mut files = Vec::new();
+        if tokio::fs::metadata(&path).await?.is_dir() {
+            let mut entries = tokio::fs::read_dir(&path).await?;
+            while let Some(entry) = entries.next_entry().await? {
+                let file = entry.path();
+                if matches!(
+                    file.extension().and_then(|e| e.to_str()),
+                    Some("json" | "ndjson" | "jsonl")
+                ) {
+                    files.push(file);
+                }
+            }
+            files.sort();
+        } else {
+            files.push(path);
+        }
+
+        let mut jobs = Vec::new();
+        for file in files {
+            let content = tokio::fs::read_to_string(&file).await?;
+            jobs.extend(parse_jobs(&content, &file)?);
+        }
+        for job in &mut jobs {
+            if job.company_name.is_empty() {
+                job.company_name = company.name.clone();
+            }
+        }
+        Ok(jobs)
+    }
+
+    async fn get_job_details(&self, job: &Job, _company: &Company) -> ScannerResult<Job> {
+        Ok(job.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_jobs_by_extension() {
+        let ndjson = "{\"id\":\"1\",\"title\":\"A\",\"location\":\"\",\"external_path\":\"\",\"company_name\":\"\"}\n\n\
+                      {\"id\":\"2\",\"title\":\"B\",\"location\":\"\",\"external_path\":\"\",\"company_name\":\"\",\"description\":\"<p>Hi</p>\"}\n";
+        let jobs = parse_jobs(ndjson, Path::new("jobs.ndjson")).unwrap();
+        assert_eq!(jobs.len(), 2);
+        assert_eq!(jobs[1].description.as_deref(), Some("<p>Hi</p>"));
+
+        let single = r#"{"id":"3","title":"C","location":"","external_path":"","company_name":""}"#;
+        assert_eq!(
+            parse_jobs(single, Path::new("job.json")).unwrap()[0].id,
+            "3"
+        );
+        let array = format!("[{single}, {single}]");
+        assert_eq!(parse_jobs(&array, Path::new("jobs.json")).unwrap().len(), 2);
+        assert!(parse_jobs("{", Path::new("jobs.json")).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_round_trip() {
+        let dir = std::env::temp_dir().join(format!("file-source-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let jobs = vec![Job {
+            id: "42".to_string(),
+            title: "Backend Engineer".to_string(),
+            description: Some("Rust".to_string()),
+            ..Default::default()
+        }];
+        write_snapshot(&dir.join("acme.ndjson"), &jobs).unwrap();
+        std::fs::write(dir.join("notes.txt"), "ignored").unwrap();
+
+        let company = Company {
+            name: "Acme".to_string(),
+            url: Url::from_directory_path(&dir).unwrap().to_string(),
+            ..Default::default()
+        };
+        let replayed = FileJobSource::new().get_job_list(&company).await.unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(replayed.len(), 1);
+        assert_eq!(replayed[0].company_name, "Acme");
+        assert_eq!(replayed[0].description.as_deref(), Some("Rust"));
+    }
+}
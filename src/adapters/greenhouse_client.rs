@@ -0,0 +1,221 @@
+use crate::adapters::http::HttpClient;
+use crate::error::{ScannerError, ScannerResult};
+use crate::{
+    core::model::{Company, Job},
+    domain::job_source::JobSource,
+};
+use async_trait::async_trait;
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+const API_BASE: &str = "https://boards-api.greenhouse.io/v1/boards";
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GreenhouseName {
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GreenhouseOffice {
+    pub name: String,
+    pub location: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GreenhouseJob {
+    pub id: u64,
+    pub title: String,
+    pub absolute_url: String,
+    pub location: Option<GreenhouseName>,
+    pub updated_at: Option<String>,
+    pub first_published: Option<String>,
+    pub requisition_id: Option<String>,
+    /// Entity-escaped HTML, only present with `?content=true`.
+    pub content: Option<String>,
+    #[serde(default)]
+    pub departments: Vec<GreenhouseName>,
+    #[serde(default)]
+    pub offices: Vec<GreenhouseOffice>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GreenhouseJobList {
+    pub jobs: Vec<GreenhouseJob>,
+}
+
+impl GreenhouseJob {
+    pub fn into_job(self, company_name: &str) -> Job {
+        let location = self
+            .location
+            .map(|l| l.name)
+            .filter(|l| !l.trim().is_empty())
+            .unwrap_or_else(|| {
+                self.offices
+                    .iter()
+                    .map(|o| o.location.as_deref().unwrap_or(&o.name))
+                    .collect::<Vec<_>>()
+                    .join("; ")
+            });
+        let department = (!self.departments.is_empty()).then(|| {
+            self.departments
+                .iter()
+                .map(|d| d.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        });
+        let posted_at = self
+            .first_published
+            .or(self.updated_at)
+            .and_then(|d| DateTime::parse_from_rfc3339(&d).ok())
+            .map(|d| d.date_naive());
+        Job {
+            id: self.id.to_string(),
+            title: self.title,
+            location,
+            description: self.content,
+            external_path: self.absolute_url,
+            company_name: company_name.to_string(),
+            posted_at,
+            requisition_id: self.requisition_id,
+            department,
+            ..Default::default()
+        }
+    }
+}
+
+/// Extracts the board token from `boards.greenhouse.io/{token}`,
+/// `job-boards.greenhouse.io/{token}`, `...?for={token}` embeds or API URLs.
+pub fn greenhouse_board_token(company_url: &str) -> ScannerResult<String> {
+    let invalid =
+        |reason: &str| ScannerError::SourceUrlError(company_url.to_string(), reason.to_string());
+
+    let parsed_url = Url::parse(company_url)?;
+    let domain = parsed_url.domain().ok_or_else(|| invalid("missing host"))?;
+    if !domain.ends_with("greenhouse.io") {
+        return Err(invalid("host is not a greenhouse.io domain"));
+    }
+    if let Some((_, token)) = parsed_url.query_pairs().find(|(k, _)| k == "for") {
+        return Ok(token.into_owned());
+    }
+    let path_segments: Vec<&str> = parsed_url
+        .path_segments()
+        .map(|segments| segments.filter(|s| !s.is_empty()).collect())
+        .unwrap_or_default();
+    match path_segments.as_slice() {
+        ["v1", "boards", token, ..] => Ok(token.to_string()),
+        ["v1", ..] => Err(invalid("API path must look like /v1/boards/{token}")),
+        [token, ..] if *token != "embed" => Ok(token.to_string()),
+        _ => Err(invalid("missing board token in path")),
+    }
+}
+
+/// Reads a company's public Greenhouse job board.
+pub struct GreenhouseClient {
+    http: HttpClient,
+}
+
+impl Default for GreenhouseClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GreenhouseClient {
+    pub fn new() -> Self {
+        GreenhouseClient {
+            http: HttpClient::default(),
+        }
+    }
+
+    pub fn with_http_client(mut self, http: HttpClient) -> Self {
+        self.http = http;
+        self
+    }
+}
+
+#[async_trait]
+impl JobSource for GreenhouseClient {
+    /// Greenhouse returns the whole board in one response, descriptions included.
+    async fn get_job_list(&self, company: &Company) -> ScannerResult<Vec<Job>> {
+        let token = greenhouse_board_token(&company.url)?;
+        let url = format!("{API_BASE}/{token}/jobs?content=true");
+        let resp = self.http.send(self.http.get(&url)).await?;
+        let list = resp.json::<GreenhouseJobList>().await?;
+        Ok(list
+            .jobs
+            .into_iter()
+            .map(|job| job.into_job(&company.name))
+            .collect())
+    }
+
+    async fn get_job_details(&self, job: &Job, company: &Company) -> ScannerResult<Job> {
+        if job.description.is_some() {
+            return Ok(job.clone());
+        }
+        let token = greenhouse_board_token(&company.url)?;
+        let url = format!("{API_BASE}/{token}/jobs/{}", job.id);
+        let resp = self.http.send(self.http.get(&url)).await?;
+        let details = resp.json::<GreenhouseJob>().await?;
+        Ok(Job {
+            description: details.content,
+            ..job.clone()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_parse_greenhouse_board_token() {
+        for url in [
+            "https://boards.greenhouse.io/acme",
+            "https://job-boards.greenhouse.io/acme/jobs/4012345",
+            "https://job-boards.eu.greenhouse.io/acme",
+            "https://boards.greenhouse.io/embed/job_board?for=acme",
+            "https://boards-api.greenhouse.io/v1/boards/acme/jobs",
+        ] {
+            assert_eq!(greenhouse_board_token(url).unwrap(), "acme", "{url}");
+        }
+        assert!(greenhouse_board_token("https://acme.com/careers").is_err());
+        assert!(greenhouse_board_token("https://boards.greenhouse.io/").is_err());
+    }
+
+    #[test]
+    fn test_greenhouse_job_maps_departments_offices_and_location() {
+        let json = r#"
+            {
+                "jobs": [
+                    {
+                        "id": 4012345,
+                        "title": "Backend Engineer",
+                        "absolute_url": "https://boards.greenhouse.io/acme/jobs/4012345",
+                        "location": { "name": "" },
+                        "updated_at": "2024-05-03T10:00:00-04:00",
+                        "first_published": "2024-05-01T09:30:00-04:00",
+                        "requisition_id": "ENG-42",
+                        "content": "&lt;p&gt;Build things&lt;/p&gt;",
+                        "departments": [{ "id": 1, "name": "Engineering" }, { "id": 2, "name": "Platform" }],
+                        "offices": [{ "id": 9, "name": "Pune", "location": "Pune, Maharashtra, India" }]
+                    }
+                ],
+                "meta": { "total": 1 }
+            }
+        "#;
+        let list = serde_json::from_str::<GreenhouseJobList>(json).unwrap();
+        let job = list.jobs.into_iter().next().unwrap().into_job("Acme");
+
+        assert_eq!(job.id, "4012345");
+        assert_eq!(job.location, "Pune, Maharashtra, India");
+        assert_eq!(job.department.as_deref(), Some("Engineering, Platform"));
+        assert_eq!(job.posted_at, NaiveDate::from_ymd_opt(2024, 5, 1));
+        assert_eq!(job.requisition_id.as_deref(), Some("ENG-42"));
+        assert_eq!(
+            job.apply_url("https://boards.greenhouse.io/acme"),
+            "https://boards.greenhouse.io/acme/jobs/4012345"
+        );
+    }
+}
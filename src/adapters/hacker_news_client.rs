@@ -0,0 +1,400 @@
+use crate::adapters::http::HttpClient;
+use crate::core::description::normalize_description;
+use crate::domain::llm::LLMProvider;
+use crate::error::{ScannerError, ScannerResult};
+use crate::{
+    core::model::{Company, ExtractedPosting, Job, RemoteType},
+    domain::job_source::JobSource,
+};
+use async_trait::async_trait;
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use url::Url;
+
+const ALGOLIA_API: &str = "https://hn.algolia.com/api/v1";
+const ITEM_URL: &str = "https://news.ycombinator.com/item?id=";
+const THREAD_TITLE: &str = "Ask HN: Who is hiring?";
+
+/// A story or comment as returned by Algolia's `/items/{id}`, with its whole reply tree.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct HnItem {
+    pub id: u64,
+    pub created_at: Option<String>,
+    pub author: Option<String>,
+    pub title: Option<String>,
+    /// Comment HTML; `null` for deleted or flagged comments.
+    pub text: Option<String>,
+    #[serde(default)]
+    pub children: Vec<HnItem>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[allow(non_snake_case)]
+pub struct HnSearchHit {
+    pub objectID: String,
+    pub title: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct HnSearchResult {
+    pub hits: Vec<HnSearchHit>,
+}
+
+/// First line of a comment, by convention a `Company | Role | Location | Remote` header.
+fn header_line(comment_html: &str) -> String {
+    normalize_description(comment_html)
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .chars()
+        .take(200)
+        .collect()
+}
+
+impl HnItem {
+    /// Top level comments are job ads; their first line stands in for the title until
+    /// `HackerNewsClient` reads the posting.
+    pub fn into_job(self, company_name: &str) -> Option<Job> {
+        let text = self.text?;
+        Some(Job {
+            id: format!("hn-{}", self.id),
+            title: header_line(&text),
+            description: Some(text),
+            external_path: format!("{ITEM_URL}{}", self.id),
+            company_name: company_name.to_string(),
+            posted_at: self
+                .created_at
+                .and_then(|d| DateTime::parse_from_rfc3339(&d).ok())
+                .map(|d| d.date_naive()),
+            ..Default::default()
+        })
+    }
+}
+
+/// Reads a posting header such as `Acme | Senior Engineer | Berlin | REMOTE (EU)`, the
+/// fallback when no LLM is configured or it fails.
+pub fn parse_header(header: &str) -> ExtractedPosting {
+    let parts: Vec<&str> = header
+        .split('|')
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .collect();
+    let remote_type = parts.iter().find_map(|p| RemoteType::from_label(p));
+    let mut rest = parts
+        .iter()
+        .skip(1)
+        .filter(|p| RemoteType::from_label(p).is_none())
+        .filter(|p| !p.starts_with("http") && !p.contains('$'));
+    ExtractedPosting {
+        company: parts.first().map(|c| c.to_string()).unwrap_or_default(),
+        title: rest.next().map(|t| t.to_string()).unwrap_or_default(),
+        location: rest.next().map(|l| l.to_string()).unwrap_or_default(),
+        remote_type,
+    }
+}
+
+/// Returns the thread id of a `news.ycombinator.com/item?id=...` URL, or `None` for the
+/// site root, which means "the latest Who is hiring thread".
+fn hacker_news_thread(company_url: &str) -> ScannerResult<Option<String>> {
+    let invalid =
+        |reason: &str| ScannerError::SourceUrlError(company_url.to_string(), reason.to_string());
+
+    let parsed_url = Url::parse(company_url)?;
+    if parsed_url.domain() != Some("news.ycombinator.com") {
+        return Err(invalid("host is not news.ycombinator.com"));
+    }
+    match parsed_url.path() {
+        "/item" => match parsed_url.query_pairs().find(|(k, _)| k == "id") {
+            Some((_, id)) if !id.is_empty() && id.chars().all(|c| c.is_ascii_digit()) => {
+                Ok(Some(id.into_owned()))
+            }
+            _ => Err(invalid("item URL must carry a numeric id")),
+        },
+        "" | "/" => Ok(None),
+        _ => Err(invalid("expected /item?id={thread} or the site root")),
+    }
+}
+
+/// Reads a monthly "Ask HN: Who is hiring?" thread through the Algolia HN API. Each top
+/// level comment becomes a job; `Company::search_queries`, when set, keep only comments
+/// mentioning one of them.
+///
+/// Company, title, location and remote policy are read before the jobs are listed, so
+/// title filtering ranks real titles: from the header line when the comment has one,
+/// otherwise from the attached LLM. `get_job_details` then asks the LLM about the
+/// header-only postings that made it through the filter.
+pub struct HackerNewsClient {
+    http: HttpClient,
+    llm: Option<Arc<dyn LLMProvider + Send + Sync>>,
+}
+
+impl Default for HackerNewsClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HackerNewsClient {
+    pub fn new() -> Self {
+        HackerNewsClient {
+            http: HttpClient::default(),
+            llm: None,
+        }
+    }
+
+    pub fn with_http_client(mut self, http: HttpClient) -> Self {
+        self.http = http;
+        self
+    }
+
+    pub fn with_llm(mut self, llm: Arc<dyn LLMProvider + Send + Sync>) -> Self {
+        self.llm = Some(llm);
+        self
+    }
+
+    async fn latest_thread(&self, company_url: &str) -> ScannerResult<String> {
+        let url =
+            format!("{ALGOLIA_API}/search_by_date?tags=story,author_whoishiring&hitsPerPage=10");
+        let resp = self.http.send(self.http.get(&url)).await?;
+        let result = resp.json::<HnSearchResult>().await?;
+        result
+            .hits
+            .into_iter()
+            .find(|hit| {
+                hit.title
+                    .as_deref()
+                    .is_some_and(|t| t.starts_with(THREAD_TITLE))
+            })
+            .map(|hit| hit.objectID)
+            .ok_or_else(|| {
+                ScannerError::SourceUrlError(
+                    company_url.to_string(),
+                    "no recent \"Who is hiring?\" thread found".to_string(),
+                )
+            })
+    }
+
+    async fn extract(&self, job: &Job) -> ExtractedPosting {
+        let comment = job.description.as_deref().unwrap_or_default();
+        let fallback = parse_header(&header_line(comment));
+        let Some(llm) = &self.llm else {
+            return fallback;
+        };
+        match llm.extract_posting(&normalize_description(comment)).await {
+            Ok(extracted) => extracted,
+            Err(e) => {
+                tracing::warn!("Falling back to the header of {}: {e}", job.id);
+                fallback
+            }
+        }
+    }
+
+    /// Reads a freshly listed comment: its header when it has one, the LLM otherwise.
+    async fn read_posting(&self, job: Job) -> Job {
+        let extracted = if has_header(&job) {
+            parse_header(&job.title)
+        } else {
+            self.extract(&job).await
+        };
+        with_posting(job, extracted)
+    }
+}
+
+fn has_header(job: &Job) -> bool {
+    header_line(job.description.as_deref().unwrap_or_default()).contains('|')
+}
+
+/// Copies what was read from a posting onto its job.
+fn with_posting(mut job: Job, extracted: ExtractedPosting) -> Job {
+    if !extracted.title.is_empty() {
+        job.title = extracted.title;
+    }
+    if !extracted.location.is_empty() {
+        job.location = extracted.location;
+    }
+    job.remote_type = extracted.remote_type.or(job.remote_type);
+    if !extracted.company.is_empty() {
+        job.company_name = extracted.company.clone();
+        job.hiring_organization = Some(extracted.company);
+    }
+    job
+}
+
+#[async_trait]
+impl JobSource for HackerNewsClient {
+    async fn get_job_list(&self, company: &Company) -> ScannerResult<Vec<Job>> {
+        let thread = match hacker_news_thread(&company.url)? {
+            Some(thread) => thread,
+            None => self.latest_thread(&company.url).await?,
+        };
+        let url = format!("{ALGOLIA_API}/items/{thread}");
+        let resp = self.http.send(self.http.get(&url)).await?;
+        let story = resp.json::<HnItem>().await?;
+        let queries: Vec<String> = company
+            .search_queries
+            .iter()
+            .map(|q| q.to_lowercase())
+            .collect();
+        let comments: Vec<Job> = story
+            .children
+            .into_iter()
+            .filter_map(|comment| comment.into_job(&company.name))
+            .filter(|job| {
+                let text = job
+                    .description
+                    .as_deref()
+                    .unwrap_or_default()
+                    .to_lowercase();
+                queries.is_empty() || queries.iter().any(|q| text.contains(q))
+            })
+            .collect();
+        let mut jobs = Vec::with_capacity(comments.len());
+        for job in comments {
+            jobs.push(self.read_posting(job).await);
+        }
+        Ok(jobs)
+    }
+
+    /// Postings listed from their header get the LLM's reading; the others already had it.
+    async fn get_job_details(&self, job: &Job, _company: &Company) -> ScannerResult<Job> {
+        if self.llm.is_none() || !has_header(job) {
+            return Ok(job.clone());
+        }
+        Ok(with_posting(job.clone(), self.extract(job).await))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::llm::AnalysisResult;
+
+    struct CannedLlm;
+
+    #[async_trait]
+    impl LLMProvider for CannedLlm {
+        fn model_name(&self) -> &str {
+            "canned"
+        }
+
+        async fn filter_titles(
+            &self,
+            title_lines: &[String],
+            _: &str,
+        ) -> ScannerResult<Vec<String>> {
+            Ok(title_lines
+                .iter()
+                .filter_map(|line| Some(line.split_once(": ")?.0.to_string()))
+                .collect())
+        }
+
+        async fn analyze_match(&self, _: &str, _: &str) -> ScannerResult<AnalysisResult> {
+            Ok(AnalysisResult {
+                is_match: false,
+                reasoning: "canned".to_string(),
+                score: 0,
+            })
+        }
+
+        async fn extract_posting(&self, posting_text: &str) -> ScannerResult<ExtractedPosting> {
+            assert!(posting_text.starts_with("Acme | Engineers"));
+            Ok(ExtractedPosting {
+                company: "Acme".to_string(),
+                title: "Backend Engineer".to_string(),
+                location: "Berlin".to_string(),
+                remote_type: Some(RemoteType::Hybrid),
+            })
+        }
+    }
+
+    #[test]
+    fn test_parse_hacker_news_thread() {
+        assert_eq!(
+            hacker_news_thread("https://news.ycombinator.com/item?id=40224213").unwrap(),
+            Some("40224213".to_string())
+        );
+        assert_eq!(
+            hacker_news_thread("https://news.ycombinator.com/").unwrap(),
+            None
+        );
+        assert!(hacker_news_thread("https://news.ycombinator.com/item?id=abc").is_err());
+        assert!(hacker_news_thread("https://news.ycombinator.com/newest").is_err());
+        assert!(hacker_news_thread("https://example.com/item?id=1").is_err());
+    }
+
+    #[test]
+    fn test_parse_header() {
+        assert_eq!(
+            parse_header("Acme | Senior Engineer | Berlin | REMOTE (EU) | https://acme.com/jobs"),
+            ExtractedPosting {
+                company: "Acme".to_string(),
+                title: "Senior Engineer".to_string(),
+                location: "Berlin".to_string(),
+                remote_type: Some(RemoteType::Remote),
+            }
+        );
+        assert_eq!(parse_header("We are hiring!").company, "We are hiring!");
+    }
+
+    #[tokio::test]
+    async fn test_comments_become_jobs_enriched_by_llm() {
+        let thread = r#"
+            {
+                "id": 40224213,
+                "title": "Ask HN: Who is hiring? (May 2024)",
+                "created_at": "2024-05-01T15:00:37.000Z",
+                "text": null,
+                "children": [
+                    {
+                        "id": 40224300,
+                        "created_at": "2024-05-01T15:02:00.000Z",
+                        "author": "acme_hiring",
+                        "text": "Acme | Engineers | Berlin | Hybrid<p>We build rockets in Rust &amp; Go.</p>",
+                        "children": [{ "id": 40224400, "text": "Are you hiring interns?", "children": [] }]
+                    },
+                    { "id": 40224301, "text": null, "children": [] }
+                ]
+            }
+        "#;
+        let story = serde_json::from_str::<HnItem>(thread).unwrap();
+        let jobs: Vec<Job> = story
+            .children
+            .into_iter()
+            .filter_map(|c| c.into_job("Hacker News"))
+            .collect();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].id, "hn-40224300");
+        assert_eq!(jobs[0].title, "Acme | Engineers | Berlin | Hybrid");
+        assert_eq!(
+            jobs[0].apply_url(""),
+            "https://news.ycombinator.com/item?id=40224300"
+        );
+
+        let company = Company {
+            name: "Hacker News".to_string(),
+            url: "https://news.ycombinator.com/".to_string(),
+            source: crate::core::model::SourceKind::HackerNews,
+            ..Default::default()
+        };
+        let without_llm = HackerNewsClient::new();
+        let listed = without_llm.read_posting(jobs[0].clone()).await;
+        assert_eq!(listed.title, "Engineers");
+        assert_eq!(listed.location, "Berlin");
+        assert_eq!(listed.company_name, "Acme");
+        assert_eq!(listed.hiring_organization.as_deref(), Some("Acme"));
+        let details = without_llm
+            .get_job_details(&listed, &company)
+            .await
+            .unwrap();
+        assert_eq!(details.title, "Engineers");
+
+        let with_llm = HackerNewsClient::new().with_llm(Arc::new(CannedLlm));
+        let listed = with_llm.read_posting(jobs[0].clone()).await;
+        assert_eq!(listed.title, "Engineers");
+        let with_llm = with_llm.get_job_details(&listed, &company).await.unwrap();
+        assert_eq!(with_llm.title, "Backend Engineer");
+        assert_eq!(with_llm.location, "Berlin");
+        assert_eq!(with_llm.remote_type, Some(RemoteType::Hybrid));
+    }
+}
@@ -0,0 +1,296 @@
+use crate::error::ScannerResult;
+use chrono::{DateTime, Utc};
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::{Client, RequestBuilder, Response, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use tracing::warn;
+#[cfg(any(test, feature = "replay"))]
+use url::Url;
+
+/// Timeouts, retry policy and politeness settings shared by the HTTP based job sources.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
+pub struct HttpConfig {
+    pub timeout_secs: u64,
+    pub connect_timeout_secs: u64,
+    /// Retries after the first attempt for 429s, 5xx responses, timeouts and connect errors.
+    pub max_retries: u32,
+    pub initial_backoff_ms: u64,
+    /// Ceiling for the computed exponential backoff; does not apply to `Retry-After`.
+    pub max_backoff_ms: u64,
+    /// Ceiling for a server supplied `Retry-After` delay, so a misbehaving host cannot
+    /// stall a scan indefinitely.
+    pub max_retry_after_secs: u64,
+    /// Upper bound on requests per second sent to a single host.
+    pub requests_per_second: f64,
+    pub user_agent: String,
+}
+
+impl Default for HttpConfig {
+    fn default() -> Self {
+        HttpConfig {
+            timeout_secs: 30,
+            connect_timeout_secs: 10,
+            max_retries: 4,
+            initial_backoff_ms: 500,
+            max_backoff_ms: 30_000,
+            max_retry_after_secs: 300,
+            requests_per_second: 2.0,
+            user_agent: format!(
+                "job-scanner/{} (+https://github.com/tusharz1/job-scanner)",
+                env!("CARGO_PKG_VERSION")
+            ),
+        }
+    }
+}
+
+impl HttpConfig {
+    /// Exponential backoff with full jitter: a random delay in `[0, min(max, initial * 2^attempt)]`.
+    fn backoff(&self, attempt: u32) -> Duration {
+        let ceiling = self
+            .initial_backoff_ms
+            .saturating_mul(1u64 << attempt.min(20))
+            .min(self.max_backoff_ms);
+        Duration::from_millis(fastrand::u64(0..=ceiling))
+    }
+
+    /// Delay before the next attempt: the server's `Retry-After` when given (up to
+    /// `max_retry_after_secs`), otherwise the jittered backoff.
+    fn retry_delay(&self, retry_after: Option<Duration>, attempt: u32) -> Duration {
+        match retry_after {
+            Some(delay) => delay.min(Duration::from_secs(self.max_retry_after_secs)),
+            None => self.backoff(attempt),
+        }
+    }
+}
+
+/// Hands out per-host send slots spaced `interval` apart.
+struct RateLimiter {
+    interval: Duration,
+    next_slot: Mutex<HashMap<String, Instant>>,
+}
+
+impl RateLimiter {
+    fn new(requests_per_second: f64) -> Self {
+        let interval = if requests_per_second > 0.0 {
+            Duration::from_secs_f64(1.0 / requests_per_second)
+        } else {
+            Duration::ZERO
+        };
+        RateLimiter {
+            interval,
+            next_slot: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Reserves the next free slot for `host` and returns when it starts.
+    async fn reserve(&self, host: &str) -> Instant {
+        let mut next_slot = self.next_slot.lock().await;
+        let now = Instant::now();
+        let slot = next_slot.get(host).map_or(now, |&next| next.max(now));
+        next_slot.insert(host.to_string(), slot + self.interval);
+        slot
+    }
+
+    async fn acquire(&self, host: &str) {
+        let slot = self.reserve(host).await;
+        tokio::time::sleep_until(slot.into()).await;
+    }
+}
+
+/// `reqwest::Client` wrapper adding retries with backoff, `Retry-After` support and a
+/// per-host rate limit.
+pub struct HttpClient {
+    client: Client,
+    config: HttpConfig,
+    limiter: RateLimiter,
+    #[cfg(any(test, feature = "replay"))]
+    base_url: Option<Url>,
+}
+
+impl Default for HttpClient {
+    fn default() -> Self {
+        Self::new(HttpConfig::default()).expect("default HTTP client configuration is valid")
+    }
+}
+
+impl HttpClient {
+    pub fn new(config: HttpConfig) -> ScannerResult<Self> {
+        let client = Client::builder()
+            .timeout(Duration::from_secs(config.timeout_secs))
+            .connect_timeout(Duration::from_secs(config.connect_timeout_secs))
+            .user_agent(config.user_agent.clone())
+            .build()?;
+        Ok(HttpClient {
+            client,
+            limiter: RateLimiter::new(config.requests_per_second),
+            config,
+            #[cfg(any(test, feature = "replay"))]
+            base_url: None,
+        })
+    }
+
+    /// Sends every request to `base_url`'s scheme, host and port instead, keeping path and
+    /// query; used to point a source at a `ReplayServer`.
+    #[cfg(any(test, feature = "replay"))]
+    pub fn with_base_url(mut self, base_url: Url) -> Self {
+        self.base_url = Some(base_url);
+        self
+    }
+
+    #[cfg(any(test, feature = "replay"))]
+    fn target(&self, url: &str) -> String {
+        let (Some(base), Ok(mut target)) = (&self.base_url, Url::parse(url)) else {
+            return url.to_string();
+        };
+        let _ = target.set_scheme(base.scheme());
+        let _ = target.set_host(base.host_str());
+        let _ = target.set_port(base.port());
+        target.to_string()
+    }
+
+    #[cfg(not(any(test, feature = "replay")))]
+    fn target(&self, url: &str) -> String {
+        url.to_string()
+    }
+
+    pub fn get(&self, url: &str) -> RequestBuilder {
+        self.client.get(self.target(url))
+    }
+
+    pub fn post(&self, url: &str) -> RequestBuilder {
+        self.client.post(self.target(url))
+    }
+
+    /// Sends `request`, retrying transient failures. Non-retryable error statuses and
+    /// exhausted retries are returned as `ApiError`.
+    pub async fn send(&self, request: RequestBuilder) -> ScannerResult<Response> {
+        let request = request.build()?;
+        let host = request.url().host_str().unwrap_or_default().to_string();
+        let mut attempt = 0;
+        loop {
+            // streaming bodies cannot be replayed, so they get a single attempt
+            let Some(attempt_request) = request.try_clone() else {
+                self.limiter.acquire(&host).await;
+                return Ok(self.client.execute(request).await?.error_for_status()?);
+            };
+            self.limiter.acquire(&host).await;
+            let retries_left = attempt < self.config.max_retries;
+
+            match self.client.execute(attempt_request).await {
+                Ok(resp) if retries_left && is_retryable(resp.status()) => {
+                    let delay = self
+                        .config
+                        .retry_delay(retry_after(resp.headers(), Utc::now()), attempt);
+                    warn!(
+                        "{} returned {}, retrying in {:?}",
+                        request.url(),
+                        resp.status(),
+                        delay
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                Ok(resp) => return Ok(resp.error_for_status()?),
+                Err(err) if retries_left && (err.is_connect() || err.is_timeout()) => {
+                    let delay = self.config.backoff(attempt);
+                    warn!("{} failed: {}, retrying in {:?}", request.url(), err, delay);
+                    tokio::time::sleep(delay).await;
+                }
+                Err(err) => return Err(err.into()),
+            }
+            attempt += 1;
+        }
+    }
+}
+
+fn is_retryable(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+/// Reads `Retry-After` given either as delta seconds or as an HTTP date.
+fn retry_after(headers: &HeaderMap, now: DateTime<Utc>) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    let at = DateTime::parse_from_rfc2822(value).ok()?;
+    Some((at.with_timezone(&Utc) - now).to_std().unwrap_or(Duration::ZERO))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn test_backoff_is_bounded() {
+        let config = HttpConfig {
+            initial_backoff_ms: 100,
+            max_backoff_ms: 1_000,
+            ..Default::default()
+        };
+        for attempt in 0..40 {
+            let ceiling = (100u64 << attempt.min(20)).min(1_000);
+            assert!(config.backoff(attempt) <= Duration::from_millis(ceiling));
+        }
+    }
+
+    #[test]
+    fn test_retry_after_is_not_capped_by_max_backoff() {
+        let config = HttpConfig {
+            max_backoff_ms: 1_000,
+            max_retry_after_secs: 120,
+            ..Default::default()
+        };
+        let delay = config.retry_delay(Some(Duration::from_secs(60)), 0);
+        assert_eq!(delay, Duration::from_secs(60));
+        let delay = config.retry_delay(Some(Duration::from_secs(3_600)), 0);
+        assert_eq!(delay, Duration::from_secs(120));
+        assert!(config.retry_delay(None, 0) <= Duration::from_millis(1_000));
+    }
+
+    #[test]
+    fn test_retry_after_parses_seconds_and_dates() {
+        let now = DateTime::parse_from_rfc2822("Wed, 21 Oct 2015 07:28:00 GMT")
+            .unwrap()
+            .with_timezone(&Utc);
+        let mut headers = HeaderMap::new();
+        assert_eq!(retry_after(&headers, now), None);
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("120"));
+        assert_eq!(retry_after(&headers, now), Some(Duration::from_secs(120)));
+
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:30 GMT"),
+        );
+        assert_eq!(retry_after(&headers, now), Some(Duration::from_secs(30)));
+
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:27:00 GMT"),
+        );
+        assert_eq!(retry_after(&headers, now), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_retryable_statuses() {
+        assert!(is_retryable(StatusCode::TOO_MANY_REQUESTS));
+        assert!(is_retryable(StatusCode::BAD_GATEWAY));
+        assert!(!is_retryable(StatusCode::NOT_FOUND));
+        assert!(!is_retryable(StatusCode::OK));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_spaces_slots_per_host() {
+        let limiter = RateLimiter::new(4.0);
+        let first = limiter.reserve("a.example").await;
+        let second = limiter.reserve("a.example").await;
+        let other = limiter.reserve("b.example").await;
+        assert_eq!(second - first, Duration::from_millis(250));
+        assert!(other < second);
+    }
+}
@@ -0,0 +1,420 @@
+use crate::adapters::http::HttpClient;
+use crate::error::{ScannerError, ScannerResult};
+use crate::{
+    core::model::{Company, Compensation, EmploymentType, Job, RemoteType},
+    domain::job_source::JobSource,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate};
+use scraper::{Html, Selector};
+use serde_json::Value;
+use std::collections::HashSet;
+use url::Url;
+
+/// Used when a company sets no `link_selector`.
+pub const DEFAULT_LINK_SELECTOR: &str = "a[href*=\"job\"]";
+
+/// Used when a company sets no `max_links`; every link is a request on each scan.
+pub const DEFAULT_MAX_LINKS: usize = 25;
+
+/// Reads a schema.org text value that may also be given as `{"name": ...}` or a list.
+fn text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.trim().to_string()).filter(|s| !s.is_empty()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Object(o) => o.get("name").or_else(|| o.get("value")).and_then(text),
+        Value::Array(a) => a.iter().find_map(text),
+        _ => None,
+    }
+}
+
+fn number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.replace(',', "").trim().parse().ok(),
+        _ => None,
+    }
+}
+
+/// Accepts both `2024-05-01` and full ISO 8601 date-times.
+fn date(value: &Value) -> Option<NaiveDate> {
+    let raw = value.as_str()?.trim();
+    NaiveDate::parse_from_str(raw, "%Y-%m-%d")
+        .ok()
+        .or_else(|| {
+            DateTime::parse_from_rfc3339(raw)
+                .ok()
+                .map(|d| d.date_naive())
+        })
+        .or_else(|| raw.get(..10)?.parse().ok())
+}
+
+/// `jobLocation` is a `Place` or a list of them whose `address` is a `PostalAddress` or a
+/// plain string. Returns the readable locations and the first country found.
+fn locations(value: &Value) -> (String, Option<String>) {
+    let places = match value {
+        Value::Array(places) => places.iter().collect(),
+        place => vec![place],
+    };
+    let mut country = None;
+    let described: Vec<String> = places
+        .into_iter()
+        .filter_map(|place| {
+            let address = place.get("address").unwrap_or(place);
+            if address.is_string() {
+                return text(address);
+            }
+            if country.is_none() {
+                country = address.get("addressCountry").and_then(text);
+            }
+            let parts: Vec<String> = ["addressLocality", "addressRegion", "addressCountry"]
+                .into_iter()
+                .filter_map(|field| address.get(field).and_then(text))
+                .collect();
+            (!parts.is_empty()).then(|| parts.join(", "))
+        })
+        .collect();
+    (described.join("; "), country)
+}
+
+/// `baseSalary` is a `MonetaryAmount` whose `value` is a `QuantitativeValue` range or a
+/// bare number.
+fn compensation(value: &Value) -> Option<Compensation> {
+    let amount = value.get("value").unwrap_or(value);
+    let (min, max) = match amount {
+        Value::Object(_) => (
+            amount
+                .get("minValue")
+                .or_else(|| amount.get("value"))
+                .and_then(number),
+            amount.get("maxValue").and_then(number),
+        ),
+        other => (number(other), None),
+    };
+    if min.is_none() && max.is_none() {
+        return None;
+    }
+    Some(Compensation {
+        min,
+        max,
+        currency: value.get("currency").and_then(text),
+        interval: amount.get("unitText").and_then(text),
+        summary: None,
+    })
+}
+
+fn employment_type(value: &Value) -> Option<EmploymentType> {
+    match value {
+        Value::Array(types) => types.iter().find_map(employment_type),
+        Value::String(label) => EmploymentType::from_label(label),
+        _ => None,
+    }
+}
+
+fn is_job_posting(value: &Value) -> bool {
+    match value.get("@type") {
+        Some(Value::String(t)) => t == "JobPosting",
+        Some(Value::Array(types)) => types.iter().any(|t| t == "JobPosting"),
+        _ => false,
+    }
+}
+
+/// Collects `JobPosting` objects from a JSON-LD document, looking through top level lists
+/// and `@graph` containers.
+fn collect_postings<'a>(value: &'a Value, postings: &mut Vec<&'a Value>) {
+    match value {
+        Value::Array(items) => items.iter().for_each(|v| collect_postings(v, postings)),
+        Value::Object(_) if is_job_posting(value) => postings.push(value),
+        Value::Object(object) => {
+            if let Some(graph) = object.get("@graph") {
+                collect_postings(graph, postings);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Maps one schema.org `JobPosting` into a `Job`, identified by its `url` or, lacking one,
+/// by the page it was found on.
+pub fn job_from_json_ld(posting: &Value, page_url: &str, company_name: &str) -> Option<Job> {
+    let title = posting.get("title").and_then(text)?;
+    let url = posting
+        .get("url")
+        .and_then(text)
+        .unwrap_or_else(|| page_url.to_string());
+    let requisition_id = posting
+        .get("identifier")
+        .and_then(|id| id.get("value").and_then(text).or_else(|| text(id)));
+    let (location, country) = posting
+        .get("jobLocation")
+        .map(locations)
+        .unwrap_or_default();
+    let remote = posting
+        .get("jobLocationType")
+        .and_then(text)
+        .is_some_and(|t| t.eq_ignore_ascii_case("TELECOMMUTE"));
+    Some(Job {
+        id: url.clone(),
+        title,
+        location,
+        description: posting.get("description").and_then(text),
+        external_path: url,
+        company_name: company_name.to_string(),
+        posted_at: posting.get("datePosted").and_then(date),
+        valid_through: posting.get("validThrough").and_then(date),
+        requisition_id,
+        employment_type: posting.get("employmentType").and_then(employment_type),
+        remote_type: remote.then_some(RemoteType::Remote),
+        country,
+        hiring_organization: posting.get("hiringOrganization").and_then(text),
+        compensation: posting.get("baseSalary").and_then(compensation),
+        ..Default::default()
+    })
+}
+
+/// Extracts every `JobPosting` embedded in `application/ld+json` scripts of a page.
+/// Scripts that are not valid JSON are skipped, as sites often ship broken ones.
+pub fn parse_job_postings(html: &str, page_url: &str, company_name: &str) -> Vec<Job> {
+    let document = Html::parse_document(html);
+    let scripts = Selector::parse("script[type=\"application/ld+json\"]").expect("static selector");
+    document
+        .select(&scripts)
+        .filter_map(|script| serde_json::from_str::<Value>(&script.text().collect::<String>()).ok())
+        .flat_map(|document| {
+            let mut postings = Vec::new();
+            collect_postings(&document, &mut postings);
+            postings
+                .into_iter()
+                .filter_map(|p| job_from_json_ld(p, page_url, company_name))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Picks the posting on a detail page that `job` was listed as, matched by URL or by
+/// requisition id, as pages may embed other (related or similar) postings too.
+fn matching_posting(postings: Vec<Job>, job: &Job) -> Option<Job> {
+    postings.into_iter().find(|posting| {
+        posting.id == job.id
+            || posting.external_path == job.external_path
+            || (job.requisition_id.is_some() && posting.requisition_id == job.requisition_id)
+    })
+}
+
+/// Resolves the `href` of every element matching `link_selector` against the listing URL,
+/// keeping http(s) links once and in page order.
+pub fn posting_links(
+    html: &str,
+    listing_url: &Url,
+    link_selector: &str,
+) -> ScannerResult<Vec<Url>> {
+    let selector = Selector::parse(link_selector).map_err(|e| {
+        ScannerError::SourceUrlError(
+            listing_url.to_string(),
+            format!("invalid link_selector `{link_selector}`: {e}"),
+        )
+    })?;
+    let document = Html::parse_document(html);
+    let mut seen = HashSet::new();
+    Ok(document
+        .select(&selector)
+        .filter_map(|a| listing_url.join(a.value().attr("href")?).ok())
+        .filter(|url| matches!(url.scheme(), "http" | "https"))
+        .map(|mut url| {
+            url.set_fragment(None);
+            url
+        })
+        .filter(|url| seen.insert(url.to_string()))
+        .collect())
+}
+
+/// Crawls self-hosted career sites that embed schema.org `JobPosting` data: the links
+/// matching `Company::link_selector` on the `Company::url` listing page are fetched and
+/// every posting found on them becomes a `Job`, description included.
+pub struct JsonLdJobSource {
+    http: HttpClient,
+    max_links: usize,
+}
+
+impl Default for JsonLdJobSource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl JsonLdJobSource {
+    pub fn new() -> Self {
+        JsonLdJobSource {
+            http: HttpClient::default(),
+            max_links: DEFAULT_MAX_LINKS,
+        }
+    }
+
+    /// Default for companies that set no `max_links`.
+    pub fn with_max_links(mut self, max_links: usize) -> Self {
+        self.max_links = max_links;
+        self
+    }
+
+    pub fn with_http_client(mut self, http: HttpClient) -> Self {
+        self.http = http;
+        self
+    }
+
+    async fn fetch_text(&self, url: &str) -> ScannerResult<String> {
+        let resp = self.http.send(self.http.get(url)).await?;
+        Ok(resp.text().await?)
+    }
+}
+
+#[async_trait]
+impl JobSource for JsonLdJobSource {
+    /// A detail page that fails to load is logged and skipped rather than failing the company.
+    async fn get_job_list(&self, company: &Company) -> ScannerResult<Vec<Job>> {
+        let listing_url = Url::parse(&company.url)?;
+        let listing = self.fetch_text(listing_url.as_str()).await?;
+        let link_selector = company
+            .link_selector
+            .as_deref()
+            .unwrap_or(DEFAULT_LINK_SELECTOR);
+        let links = posting_links(&listing, &listing_url, link_selector)?;
+        let max_links = company.max_links.unwrap_or(self.max_links);
+
+        let mut seen = HashSet::new();
+        let mut jobs = Vec::new();
+        for link in links.into_iter().take(max_links) {
+            let page = match self.fetch_text(link.as_str()).await {
+                Ok(page) => page,
+                Err(e) => {
+                    tracing::warn!("Skipping {link} for {}: {e}", company.name);
+                    continue;
+                }
+            };
+            jobs.extend(
+                parse_job_postings(&page, link.as_str(), &company.name)
+                    .into_iter()
+                    .filter(|job| seen.insert(job.id.clone())),
+            );
+        }
+        Ok(jobs)
+    }
+
+    /// Postings are read from their detail pages, so the description is usually already
+    /// there; otherwise the page is fetched again and the posting it was listed as is kept.
+    async fn get_job_details(&self, job: &Job, company: &Company) -> ScannerResult<Job> {
+        if job.description.is_some() {
+            return Ok(job.clone());
+        }
+        let page = self.fetch_text(&job.external_path).await?;
+        let postings = parse_job_postings(&page, &job.id, &company.name);
+        Ok(matching_posting(postings, job).unwrap_or_else(|| job.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_posting_links_follow_selector() {
+        let listing = Url::parse("https://acme.com/careers/").unwrap();
+        let html = r##"
+            <a href="/careers/jobs/backend-engineer">Backend Engineer</a>
+            <a href="jobs/backend-engineer#apply">Backend Engineer (again)</a>
+            <a href="mailto:jobs@acme.com">Email us</a>
+            <a class="nav" href="/about">About</a>
+        "##;
+        let links = posting_links(html, &listing, DEFAULT_LINK_SELECTOR).unwrap();
+        assert_eq!(
+            links.iter().map(Url::as_str).collect::<Vec<_>>(),
+            ["https://acme.com/careers/jobs/backend-engineer"]
+        );
+        assert_eq!(posting_links(html, &listing, "a.nav").unwrap().len(), 1);
+        assert!(posting_links(html, &listing, "a[").is_err());
+    }
+
+    #[test]
+    fn test_details_keep_the_requested_posting() {
+        let job = |url: &str, requisition_id: Option<&str>| Job {
+            id: url.to_string(),
+            external_path: url.to_string(),
+            requisition_id: requisition_id.map(str::to_string),
+            ..Default::default()
+        };
+        let listed = job("https://acme.com/jobs/backend", Some("BE-7"));
+        let related = job("https://acme.com/jobs/frontend", Some("FE-2"));
+        let moved = job("https://acme.com/jobs/backend?ref=1", Some("BE-7"));
+
+        let found = matching_posting(vec![related.clone(), listed.clone()], &listed);
+        assert_eq!(found.unwrap().id, listed.id);
+        let found = matching_posting(vec![related.clone(), moved.clone()], &listed);
+        assert_eq!(found.unwrap().id, moved.id);
+        assert!(matching_posting(vec![related], &listed).is_none());
+    }
+
+    #[test]
+    fn test_parse_job_postings_from_graph() {
+        let html = r#"
+            <html><head>
+            <script type="application/ld+json">{ not json }</script>
+            <script type="application/ld+json">
+            {
+                "@context": "https://schema.org",
+                "@graph": [
+                    { "@type": "Organization", "name": "Acme" },
+                    {
+                        "@type": "JobPosting",
+                        "title": "Backend Engineer",
+                        "identifier": { "@type": "PropertyValue", "name": "Acme", "value": "BE-7" },
+                        "datePosted": "2024-05-01T09:00:00+02:00",
+                        "validThrough": "2024-06-30",
+                        "employmentType": ["FULL_TIME", "CONTRACTOR"],
+                        "hiringOrganization": { "@type": "Organization", "name": "Acme GmbH" },
+                        "jobLocation": [
+                            { "@type": "Place", "address": { "@type": "PostalAddress", "addressLocality": "Berlin", "addressCountry": "DE" } },
+                            { "@type": "Place", "address": "Hamburg, Germany" }
+                        ],
+                        "jobLocationType": "TELECOMMUTE",
+                        "baseSalary": {
+                            "@type": "MonetaryAmount",
+                            "currency": "EUR",
+                            "value": { "@type": "QuantitativeValue", "minValue": 70000, "maxValue": "85,000", "unitText": "YEAR" }
+                        },
+                        "description": "&lt;p&gt;Build APIs.&lt;/p&gt;"
+                    }
+                ]
+            }
+            </script>
+            </head></html>
+        "#;
+        let url = "https://acme.com/careers/jobs/backend-engineer";
+        let jobs = parse_job_postings(html, url, "Acme");
+        assert_eq!(jobs.len(), 1);
+        let job = &jobs[0];
+        assert_eq!(job.id, url);
+        assert_eq!(job.external_path, url);
+        assert_eq!(job.title, "Backend Engineer");
+        assert_eq!(job.requisition_id.as_deref(), Some("BE-7"));
+        assert_eq!(job.location, "Berlin, DE; Hamburg, Germany");
+        assert_eq!(job.country.as_deref(), Some("DE"));
+        assert_eq!(job.hiring_organization.as_deref(), Some("Acme GmbH"));
+        assert_eq!(job.employment_type, Some(EmploymentType::FullTime));
+        assert_eq!(job.remote_type, Some(RemoteType::Remote));
+        assert_eq!(job.posted_at, NaiveDate::from_ymd_opt(2024, 5, 1));
+        assert_eq!(job.valid_through, NaiveDate::from_ymd_opt(2024, 6, 30));
+        assert_eq!(
+            job.description.as_deref(),
+            Some("&lt;p&gt;Build APIs.&lt;/p&gt;")
+        );
+        assert_eq!(
+            job.compensation,
+            Some(Compensation {
+                min: Some(70000.0),
+                max: Some(85000.0),
+                currency: Some("EUR".to_string()),
+                interval: Some("YEAR".to_string()),
+                summary: None,
+            })
+        );
+    }
+}
@@ -0,0 +1,321 @@
+use crate::adapters::http::HttpClient;
+use crate::error::{ScannerError, ScannerResult};
+use crate::{
+    core::model::{Company, EmploymentType, Job, RemoteType},
+    domain::job_source::JobSource,
+};
+use async_trait::async_trait;
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use url::Url;
+
+const PAGE_SIZE: usize = 100;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct LeverCategories {
+    pub team: Option<String>,
+    pub department: Option<String>,
+    pub location: Option<String>,
+    pub commitment: Option<String>,
+    #[serde(default)]
+    pub allLocations: Vec<String>,
+}
+
+/// A titled HTML fragment such as "Requirements" with its `<li>` items.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LeverList {
+    pub text: String,
+    pub content: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[allow(non_snake_case)]
+pub struct LeverPosting {
+    pub id: String,
+    pub text: String,
+    pub hostedUrl: String,
+    /// Milliseconds since the epoch.
+    pub createdAt: Option<i64>,
+    #[serde(default)]
+    pub categories: LeverCategories,
+    pub description: Option<String>,
+    pub descriptionPlain: Option<String>,
+    #[serde(default)]
+    pub lists: Vec<LeverList>,
+    pub additional: Option<String>,
+    pub workplaceType: Option<String>,
+    pub country: Option<String>,
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('\n', "<br>")
+}
+
+impl LeverPosting {
+    /// Joins the opening text, every list and the closing section into one HTML document.
+    fn full_description(&self) -> Option<String> {
+        let mut html = self
+            .description
+            .clone()
+            .or_else(|| self.descriptionPlain.as_deref().map(escape_html))?;
+        for list in &self.lists {
+            html.push_str(&format!(
+                "<h3>{}</h3><ul>{}</ul>",
+                escape_html(&list.text),
+                list.content
+            ));
+        }
+        if let Some(additional) = &self.additional {
+            html.push_str(additional);
+        }
+        Some(html)
+    }
+
+    pub fn into_job(self, company_name: &str) -> Job {
+        let categories = &self.categories;
+        let location = categories
+            .location
+            .clone()
+            .unwrap_or_else(|| categories.allLocations.join("; "));
+        Job {
+            id: self.id.clone(),
+            title: self.text.clone(),
+            location,
+            description: self.full_description(),
+            external_path: self.hostedUrl.clone(),
+            company_name: company_name.to_string(),
+            posted_at: self
+                .createdAt
+                .and_then(DateTime::from_timestamp_millis)
+                .map(|d| d.date_naive()),
+            employment_type: categories
+                .commitment
+                .as_deref()
+                .and_then(EmploymentType::from_label),
+            remote_type: self
+                .workplaceType
+                .as_deref()
+                .and_then(RemoteType::from_label),
+            country: self.country.clone(),
+            department: categories.team.clone().or(categories.department.clone()),
+            ..Default::default()
+        }
+    }
+}
+
+/// Returns the postings API base for `jobs.lever.co/{company}`, `jobs.eu.lever.co/{company}`
+/// or an `api.lever.co/v0/postings/{company}` URL.
+pub fn lever_api_url(company_url: &str) -> ScannerResult<String> {
+    let invalid =
+        |reason: &str| ScannerError::SourceUrlError(company_url.to_string(), reason.to_string());
+
+    let parsed_url = Url::parse(company_url)?;
+    let domain = parsed_url.domain().ok_or_else(|| invalid("missing host"))?;
+    let api_host = match domain {
+        "jobs.lever.co" | "api.lever.co" => "api.lever.co",
+        "jobs.eu.lever.co" | "api.eu.lever.co" => "api.eu.lever.co",
+        _ => return Err(invalid("host is not a jobs.lever.co or api.lever.co domain")),
+    };
+    let path_segments: Vec<&str> = parsed_url
+        .path_segments()
+        .map(|segments| segments.filter(|s| !s.is_empty()).collect())
+        .unwrap_or_default();
+    let company = match path_segments.as_slice() {
+        ["v0", "postings", company, ..] => *company,
+        ["v0", ..] => return Err(invalid("API path must look like /v0/postings/{company}")),
+        [company, ..] => *company,
+        [] => return Err(invalid("missing company name in path")),
+    };
+    Ok(format!("https://{api_host}/v0/postings/{company}"))
+}
+
+/// Reads a company's public Lever postings.
+pub struct LeverClient {
+    http: HttpClient,
+    max_pages: usize,
+}
+
+impl Default for LeverClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LeverClient {
+    pub fn new() -> Self {
+        LeverClient {
+            http: HttpClient::default(),
+            max_pages: 50,
+        }
+    }
+
+    /// Upper bound on pages fetched per company, as a guard against endpoints that
+    /// ignore `skip`.
+    pub fn with_max_pages(mut self, max_pages: usize) -> Self {
+        self.max_pages = max_pages.max(1);
+        self
+    }
+
+    pub fn with_http_client(mut self, http: HttpClient) -> Self {
+        self.http = http;
+        self
+    }
+}
+
+#[async_trait]
+impl JobSource for LeverClient {
+    /// Postings come with their full content, so `get_job_details` normally has nothing to fetch.
+    /// Paging stops at a short page, at a page with no new postings or after `max_pages`.
+    async fn get_job_list(&self, company: &Company) -> ScannerResult<Vec<Job>> {
+        let api_url = lever_api_url(&company.url)?;
+        let mut seen = HashSet::new();
+        let mut jobs = Vec::new();
+        for page_number in 0..self.max_pages {
+            let url = format!(
+                "{api_url}?mode=json&skip={}&limit={PAGE_SIZE}",
+                page_number * PAGE_SIZE
+            );
+            let resp = self.http.send(self.http.get(&url)).await?;
+            let page = resp.json::<Vec<LeverPosting>>().await?;
+            let page_len = page.len();
+            let before = jobs.len();
+            jobs.extend(
+                page.into_iter()
+                    .filter(|p| seen.insert(p.id.clone()))
+                    .map(|p| p.into_job(&company.name)),
+            );
+            if page_len < PAGE_SIZE || jobs.len() == before {
+                break;
+            }
+        }
+        Ok(jobs)
+    }
+
+    async fn get_job_details(&self, job: &Job, company: &Company) -> ScannerResult<Job> {
+        if job.description.is_some() {
+            return Ok(job.clone());
+        }
+        let api_url = lever_api_url(&company.url)?;
+        let url = format!("{api_url}/{}?mode=json", job.id);
+        let resp = self.http.send(self.http.get(&url)).await?;
+        let posting = resp.json::<LeverPosting>().await?;
+        Ok(Job {
+            description: posting.full_description(),
+            ..job.clone()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::http::HttpConfig;
+    use crate::adapters::replay::ReplayServer;
+    use crate::core::description::normalize_description;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_parse_lever_api_url() {
+        for url in [
+            "https://jobs.lever.co/acme",
+            "https://jobs.lever.co/acme/5ac21346-8e0c-4494-8e7a-3eb92ff77902",
+            "https://api.lever.co/v0/postings/acme?mode=json",
+        ] {
+            assert_eq!(
+                lever_api_url(url).unwrap(),
+                "https://api.lever.co/v0/postings/acme",
+                "{url}"
+            );
+        }
+        assert_eq!(
+            lever_api_url("https://jobs.eu.lever.co/acme").unwrap(),
+            "https://api.eu.lever.co/v0/postings/acme"
+        );
+        assert!(lever_api_url("https://lever.co/acme").is_err());
+        assert!(lever_api_url("https://jobs.lever.co/").is_err());
+    }
+
+    #[test]
+    fn test_lever_posting_maps_categories_and_content() {
+        let json = r#"
+            [
+                {
+                    "id": "5ac21346-8e0c-4494-8e7a-3eb92ff77902",
+                    "text": "Platform Engineer",
+                    "hostedUrl": "https://jobs.lever.co/acme/5ac21346-8e0c-4494-8e7a-3eb92ff77902",
+                    "applyUrl": "https://jobs.lever.co/acme/5ac21346-8e0c-4494-8e7a-3eb92ff77902/apply",
+                    "createdAt": 1714557600000,
+                    "categories": {
+                        "team": "Infrastructure",
+                        "department": "Engineering",
+                        "location": "Berlin",
+                        "commitment": "Full-time",
+                        "allLocations": ["Berlin", "Remote - EU"]
+                    },
+                    "descriptionPlain": "We run Kubernetes.\nLots of it.",
+                    "lists": [
+                        { "text": "What you'll do", "content": "<li>Operate clusters</li><li>Write Rust</li>" }
+                    ],
+                    "additional": "<p>Visa sponsorship available.</p>",
+                    "workplaceType": "hybrid",
+                    "country": "DE"
+                }
+            ]
+        "#;
+        let postings = serde_json::from_str::<Vec<LeverPosting>>(json).unwrap();
+        let job = postings.into_iter().next().unwrap().into_job("Acme");
+
+        assert_eq!(job.id, "5ac21346-8e0c-4494-8e7a-3eb92ff77902");
+        assert_eq!(job.title, "Platform Engineer");
+        assert_eq!(job.location, "Berlin");
+        assert_eq!(job.department.as_deref(), Some("Infrastructure"));
+        assert_eq!(job.employment_type, Some(EmploymentType::FullTime));
+        assert_eq!(job.remote_type, Some(RemoteType::Hybrid));
+        assert_eq!(job.country.as_deref(), Some("DE"));
+        assert_eq!(job.posted_at, NaiveDate::from_ymd_opt(2024, 5, 1));
+        assert_eq!(
+            normalize_description(job.description.as_deref().unwrap()),
+            "We run Kubernetes.\nLots of it.\n\n\
+             ## What you'll do\n\n\
+             - Operate clusters\n- Write Rust\n\n\
+             Visa sponsorship available."
+        );
+    }
+
+    #[tokio::test]
+    async fn test_paging_stops_when_skip_is_ignored() {
+        // both recorded pages hold the same 100 postings
+        let server = ReplayServer::start(
+            concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/tests/fixtures/replay/lever_ignores_skip"
+            ),
+            "https://api.lever.co",
+        )
+        .await
+        .unwrap();
+        let http = HttpClient::new(HttpConfig {
+            max_retries: 0,
+            requests_per_second: 0.0,
+            ..Default::default()
+        })
+        .unwrap()
+        .with_base_url(server.url().clone());
+        let company = Company {
+            name: "Acme".to_string(),
+            url: "https://jobs.lever.co/acme".to_string(),
+            ..Default::default()
+        };
+        let jobs = LeverClient::new()
+            .with_http_client(http)
+            .get_job_list(&company)
+            .await
+            .unwrap();
+        assert_eq!(jobs.len(), PAGE_SIZE);
+    }
+}
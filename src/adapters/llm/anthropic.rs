@@ -0,0 +1,273 @@
+use crate::adapters::http::{HttpClient, HttpConfig};
+use crate::adapters::llm::prompts;
+use crate::adapters::llm::schema::response_schema;
+use crate::core::model::{ExtractedPosting, LlmAnalysis, MatchingJobTitles};
+use crate::domain::llm::{AnalysisResult, LLMProvider};
+use crate::error::ScannerError::LlmError;
+use crate::error::ScannerResult;
+use async_trait::async_trait;
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use url::Url;
+
+const ANTHROPIC_API: &str = "https://api.anthropic.com/v1/";
+const ANTHROPIC_VERSION: &str = "2023-06-01";
+
+/// One block of a Messages API response; only `tool_use` blocks carry an `input`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ContentBlock {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub name: Option<String>,
+    pub input: Option<Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MessagesResponse {
+    pub content: Vec<ContentBlock>,
+    pub stop_reason: Option<String>,
+}
+
+/// Talks to the Anthropic Messages API. Structured answers come from a forced call of a
+/// single tool whose input schema is the one generated for the expected type, so the
+/// model has to answer with arguments matching it.
+pub struct AnthropicProvider {
+    http: HttpClient,
+    base_url: Url,
+    api_key: String,
+    model: String,
+    max_tokens: u32,
+    temperature: Option<f32>,
+}
+
+impl AnthropicProvider {
+    pub fn new(api_key: String, model: String) -> ScannerResult<Self> {
+        let http = HttpClient::new(HttpConfig {
+            timeout_secs: 120,
+            requests_per_second: 0.0,
+            ..Default::default()
+        })?;
+        Ok(AnthropicProvider {
+            http,
+            base_url: Url::parse(ANTHROPIC_API)?,
+            api_key,
+            model,
+            max_tokens: 1024,
+            temperature: None,
+        })
+    }
+
+    /// Talks to the API rooted at `base_url` instead, e.g. a `ReplayServer`.
+    pub fn with_base_url(mut self, base_url: &str) -> ScannerResult<Self> {
+        let mut base_url = Url::parse(base_url)?;
+        if !base_url.path().ends_with('/') {
+            base_url.set_path(&format!("{}/", base_url.path()));
+        }
+        self.base_url = base_url;
+        Ok(self)
+    }
+
+    pub fn with_max_tokens(mut self, max_tokens: u32) -> Self {
+        self.max_tokens = max_tokens;
+        self
+    }
+
+    pub fn with_temperature(mut self, temperature: f32) -> Self {
+        self.temperature = Some(temperature);
+        self
+    }
+
+    pub fn with_http_client(mut self, http: HttpClient) -> Self {
+        self.http = http;
+        self
+    }
+
+    fn request_body<T: JsonSchema>(
+        &self,
+        tool_name: &str,
+        description: &str,
+        prompt: &str,
+    ) -> Value {
+        let mut body = json!({
+            "model": self.model,
+            "max_tokens": self.max_tokens,
+            "messages": [{ "role": "user", "content": prompt }],
+            "tools": [{
+                "name": tool_name,
+                "description": description,
+                "input_schema": response_schema::<T>().to_value(),
+            }],
+            "tool_choice": { "type": "tool", "name": tool_name },
+        });
+        if let Some(temperature) = self.temperature {
+            body["temperature"] = json!(temperature);
+        }
+        body
+    }
+
+    /// Forces a call of `tool_name` and parses its input as `T`.
+    async fn tool_call<T: JsonSchema + DeserializeOwned>(
+        &self,
+        tool_name: &str,
+        description: &str,
+        prompt: &str,
+    ) -> ScannerResult<T> {
+        let url = self.base_url.join("messages")?;
+        let request = self
+            .http
+            .post(url.as_str())
+            .header("x-api-key", &self.api_key)
+            .header("anthropic-version", ANTHROPIC_VERSION)
+            .json(&self.request_body::<T>(tool_name, description, prompt));
+        let response = self
+            .http
+            .send(request)
+            .await?
+            .json::<MessagesResponse>()
+            .await?;
+        let input = response
+            .content
+            .into_iter()
+            .find(|block| block.kind == "tool_use" && block.name.as_deref() == Some(tool_name))
+            .and_then(|block| block.input)
+            .ok_or_else(|| {
+                LlmError(format!(
+                    "{} did not call {tool_name} (stop reason {:?})",
+                    self.model, response.stop_reason
+                ))
+            })?;
+        serde_json::from_value(input).map_err(|e| LlmError(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl LLMProvider for AnthropicProvider {
+    fn model_name(&self) -> &str {
+        &self.model
+    }
+
+    async fn filter_titles(
+        &self,
+        title_lines: &[String],
+        profile_summary: &str,
+    ) -> ScannerResult<Vec<String>> {
+        let prompt = prompts::filter_titles(title_lines, profile_summary);
+        let response: MatchingJobTitles = self
+            .tool_call(
+                "report_matching_jobs",
+                "Report the ids of the jobs that match the professional summary.",
+                &prompt,
+            )
+            .await?;
+        Ok(response.matching_jobs)
+    }
+
+    async fn analyze_match(
+        &self,
+        resume_text: &str,
+        job_description: &str,
+    ) -> ScannerResult<AnalysisResult> {
+        let prompt = prompts::analyze_match(resume_text, job_description);
+        let response: LlmAnalysis = self
+            .tool_call(
+                "report_analysis",
+                "Report how well the resume matches the job description.",
+                &prompt,
+            )
+            .await?;
+        Ok(AnalysisResult {
+            is_match: response.is_match,
+            score: response.score,
+            reasoning: response.reasoning,
+        })
+    }
+
+    async fn extract_posting(&self, posting_text: &str) -> ScannerResult<ExtractedPosting> {
+        let prompt = prompts::extract_posting(posting_text);
+        self.tool_call(
+            "report_posting",
+            "Report the facts read from the job posting.",
+            &prompt,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::llm::schema::example_instance;
+    use crate::adapters::replay::ReplayServer;
+    use crate::error::ScannerError;
+
+    #[test]
+    fn test_request_forces_tool_with_generated_schema() {
+        let provider =
+            AnthropicProvider::new("key".to_string(), "claude-3-5-haiku-latest".to_string())
+                .unwrap();
+        let body = provider.request_body::<LlmAnalysis>("report_analysis", "Report.", "Hi");
+        assert_eq!(
+            body["tool_choice"],
+            json!({ "type": "tool", "name": "report_analysis" })
+        );
+        let schema = &body["tools"][0]["input_schema"];
+        assert_eq!(schema["properties"]["score"]["maximum"], 100);
+        let analysis: LlmAnalysis = serde_json::from_value(example_instance(schema)).unwrap();
+        assert!(analysis.is_match);
+        assert!(body.get("temperature").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_tool_inputs_from_replayed_api() {
+        let server = ReplayServer::start(
+            concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/tests/fixtures/replay/anthropic"
+            ),
+            "https://api.anthropic.com",
+        )
+        .await
+        .unwrap();
+        let http = HttpClient::new(HttpConfig {
+            max_retries: 0,
+            requests_per_second: 0.0,
+            ..Default::default()
+        })
+        .unwrap();
+        let api_key = std::env::var("ANTHROPIC_API_KEY").unwrap_or_default();
+        let provider = AnthropicProvider::new(api_key, "claude-3-5-haiku-latest".to_string())
+            .unwrap()
+            .with_base_url(server.url().join("v1").unwrap().as_str())
+            .unwrap()
+            .with_temperature(0.0)
+            .with_http_client(http);
+
+        let lines = [
+            "R101: Backend Engineer (Pune)".to_string(),
+            "R102: Marketing Manager (Pune)".to_string(),
+        ];
+        let ids = provider
+            .filter_titles(&lines, "Backend engineer, Java and Rust")
+            .await
+            .unwrap();
+        assert_eq!(ids, ["R101"]);
+
+        let analysis = provider
+            .analyze_match(
+                "Backend engineer, Java and Rust",
+                "Build payment APIs in Rust.",
+            )
+            .await
+            .unwrap();
+        assert!(analysis.is_match);
+        assert_eq!(analysis.score, 85);
+
+        // Answered with text only, e.g. after hitting max_tokens.
+        assert!(matches!(
+            provider.extract_posting("Acme | Engineers | Berlin").await,
+            Err(ScannerError::LlmError(_))
+        ));
+    }
+}
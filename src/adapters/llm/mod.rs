@@ -1 +1,61 @@
+use crate::adapters::llm::anthropic::AnthropicProvider;
+use crate::adapters::llm::openai_compatible::OpenAiCompatibleProvider;
+use crate::adapters::ollama::OllamaProvider;
+use crate::config::{LLMBackend, LLMChoice};
+use crate::domain::llm::LLMProvider;
+use crate::error::{ScannerError, ScannerResult};
+use std::sync::Arc;
+use url::Url;
+
+pub mod anthropic;
+pub mod openai_compatible;
 pub mod openrouter;
+pub mod prompts;
+pub mod schema;
+
+/// Builds the provider configured in the `llm` section of `data/companies.json`.
+pub fn provider_from_config(
+    choice: &LLMChoice,
+) -> ScannerResult<Arc<dyn LLMProvider + Send + Sync>> {
+    let api_key_env = match (&choice.api_key_env, choice.provider) {
+        (Some(name), _) => Some(name.as_str()),
+        (None, LLMBackend::Anthropic) => Some("ANTHROPIC_API_KEY"),
+        (None, _) => None,
+    };
+    let api_key = match api_key_env {
+        Some(name) => Some(std::env::var(name).map_err(|_| {
+            ScannerError::LlmError(format!("environment variable {name} is not set"))
+        })?),
+        None => None,
+    };
+    let model = choice.model_name.clone();
+    Ok(match choice.provider {
+        LLMBackend::Ollama => match &choice.base_url {
+            Some(base_url) => Arc::new(OllamaProvider::with_url(model, Url::parse(base_url)?)),
+            None => Arc::new(OllamaProvider::new(model)),
+        },
+        LLMBackend::OpenaiCompatible => {
+            let base_url = choice.base_url.as_deref().ok_or_else(|| {
+                ScannerError::LlmError("openai_compatible needs a base_url".to_string())
+            })?;
+            let mut provider = OpenAiCompatibleProvider::new(base_url, model)?;
+            if let Some(api_key) = api_key {
+                provider = provider.with_api_key(api_key);
+            }
+            if let Some(temperature) = choice.temperature {
+                provider = provider.with_temperature(temperature);
+            }
+            Arc::new(provider)
+        }
+        LLMBackend::Anthropic => {
+            let mut provider = AnthropicProvider::new(api_key.unwrap_or_default(), model)?;
+            if let Some(base_url) = &choice.base_url {
+                provider = provider.with_base_url(base_url)?;
+            }
+            if let Some(temperature) = choice.temperature {
+                provider = provider.with_temperature(temperature);
+            }
+            Arc::new(provider)
+        }
+    })
+}
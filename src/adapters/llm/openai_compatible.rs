@@ -0,0 +1,249 @@
+use crate::adapters::http::{HttpClient, HttpConfig};
+use crate::adapters::llm::prompts;
+use crate::adapters::llm::schema::response_schema;
+use crate::core::model::{ExtractedPosting, LlmAnalysis, MatchingJobTitles};
+use crate::domain::llm::{AnalysisResult, LLMProvider};
+use crate::error::ScannerError::LlmError;
+use crate::error::ScannerResult;
+use async_trait::async_trait;
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use url::Url;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ChatMessage {
+    pub role: String,
+    pub content: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ChatChoice {
+    pub message: ChatMessage,
+    pub finish_reason: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ChatCompletion {
+    pub choices: Vec<ChatChoice>,
+}
+
+/// Talks to any server speaking the OpenAI `/v1/chat/completions` protocol with
+/// `response_format` JSON schemas: vLLM, llama.cpp server, LM Studio, OpenAI itself.
+pub struct OpenAiCompatibleProvider {
+    http: HttpClient,
+    /// API root the `chat/completions` path is resolved against, e.g. `http://localhost:8000/v1/`.
+    base_url: Url,
+    api_key: Option<String>,
+    model: String,
+    temperature: Option<f32>,
+}
+
+impl OpenAiCompatibleProvider {
+    /// `base_url` is the API root including its version, e.g. `http://localhost:1234/v1`.
+    pub fn new(base_url: &str, model: String) -> ScannerResult<Self> {
+        let mut base_url = Url::parse(base_url)?;
+        if !base_url.path().ends_with('/') {
+            base_url.set_path(&format!("{}/", base_url.path()));
+        }
+        // Local models can take minutes to answer and nothing needs politeness delays.
+        let http = HttpClient::new(HttpConfig {
+            timeout_secs: 300,
+            requests_per_second: 0.0,
+            ..Default::default()
+        })?;
+        Ok(OpenAiCompatibleProvider {
+            http,
+            base_url,
+            api_key: None,
+            model,
+            temperature: None,
+        })
+    }
+
+    /// Sent as a bearer token; most local servers need none.
+    pub fn with_api_key(mut self, api_key: String) -> Self {
+        self.api_key = Some(api_key);
+        self
+    }
+
+    pub fn with_temperature(mut self, temperature: f32) -> Self {
+        self.temperature = Some(temperature);
+        self
+    }
+
+    pub fn with_http_client(mut self, http: HttpClient) -> Self {
+        self.http = http;
+        self
+    }
+
+    fn request_body<T: JsonSchema>(&self, schema_name: &str, prompt: &str) -> Value {
+        let mut body = json!({
+            "model": self.model,
+            "messages": [{ "role": "user", "content": prompt }],
+            "response_format": {
+                "type": "json_schema",
+                "json_schema": {
+                    "name": schema_name,
+                    "strict": true,
+                    "schema": response_schema::<T>().to_value(),
+                },
+            },
+        });
+        if let Some(temperature) = self.temperature {
+            body["temperature"] = json!(temperature);
+        }
+        body
+    }
+
+    /// Sends `prompt` with a strict JSON schema generated from `T` and parses the answer.
+    async fn structured_completion<T: JsonSchema + DeserializeOwned>(
+        &self,
+        schema_name: &str,
+        prompt: &str,
+    ) -> ScannerResult<T> {
+        let url = self.base_url.join("chat/completions")?;
+        let mut request = self
+            .http
+            .post(url.as_str())
+            .json(&self.request_body::<T>(schema_name, prompt));
+        if let Some(api_key) = &self.api_key {
+            request = request.bearer_auth(api_key);
+        }
+        let completion = self
+            .http
+            .send(request)
+            .await?
+            .json::<ChatCompletion>()
+            .await?;
+        let content = completion
+            .choices
+            .into_iter()
+            .next()
+            .and_then(|choice| choice.message.content)
+            .ok_or_else(|| LlmError(format!("{} returned no message content", self.model)))?;
+        serde_json::from_str(&content).map_err(|e| LlmError(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl LLMProvider for OpenAiCompatibleProvider {
+    fn model_name(&self) -> &str {
+        &self.model
+    }
+
+    async fn filter_titles(
+        &self,
+        title_lines: &[String],
+        profile_summary: &str,
+    ) -> ScannerResult<Vec<String>> {
+        let prompt = prompts::filter_titles(title_lines, profile_summary);
+        let response: MatchingJobTitles = self
+            .structured_completion("matching_job_titles", &prompt)
+            .await?;
+        Ok(response.matching_jobs)
+    }
+
+    async fn analyze_match(
+        &self,
+        resume_text: &str,
+        job_description: &str,
+    ) -> ScannerResult<AnalysisResult> {
+        let prompt = prompts::analyze_match(resume_text, job_description);
+        let response: LlmAnalysis = self.structured_completion("llm_analysis", &prompt).await?;
+        Ok(AnalysisResult {
+            is_match: response.is_match,
+            score: response.score,
+            reasoning: response.reasoning,
+        })
+    }
+
+    async fn extract_posting(&self, posting_text: &str) -> ScannerResult<ExtractedPosting> {
+        let prompt = prompts::extract_posting(posting_text);
+        self.structured_completion("extracted_posting", &prompt)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::replay::ReplayServer;
+    use crate::error::ScannerError;
+
+    /// A stand-in for a local llama.cpp server on port 8080, replaying
+    /// `tests/fixtures/replay/openai_compatible`.
+    async fn stub_provider() -> (ReplayServer, OpenAiCompatibleProvider) {
+        let server = ReplayServer::start(
+            concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/tests/fixtures/replay/openai_compatible"
+            ),
+            "http://localhost:8080",
+        )
+        .await
+        .unwrap();
+        let http = HttpClient::new(HttpConfig {
+            max_retries: 0,
+            requests_per_second: 0.0,
+            ..Default::default()
+        })
+        .unwrap();
+        let provider = OpenAiCompatibleProvider::new(
+            server.url().join("v1").unwrap().as_str(),
+            "qwen3-8b".to_string(),
+        )
+        .unwrap()
+        .with_temperature(0.0)
+        .with_api_key("local".to_string())
+        .with_http_client(http);
+        (server, provider)
+    }
+
+    #[test]
+    fn test_request_body_carries_schema_and_temperature() {
+        let provider =
+            OpenAiCompatibleProvider::new("http://localhost:8080/v1", "qwen3-8b".to_string())
+                .unwrap()
+                .with_temperature(0.5);
+        assert_eq!(provider.base_url.as_str(), "http://localhost:8080/v1/");
+        let body = provider.request_body::<LlmAnalysis>("llm_analysis", "Hi");
+        assert_eq!(body["temperature"], 0.5);
+        assert_eq!(body["response_format"]["json_schema"]["strict"], true);
+        assert_eq!(
+            body["response_format"]["json_schema"]["schema"]["properties"]["score"]["maximum"],
+            100
+        );
+    }
+
+    #[tokio::test]
+    async fn test_structured_answers_from_stub_server() {
+        let (_server, provider) = stub_provider().await;
+        let lines = [
+            "R101: Backend Engineer (Pune)".to_string(),
+            "R102: Marketing Manager (Pune)".to_string(),
+        ];
+        let ids = provider
+            .filter_titles(&lines, "Backend engineer, Java and Rust")
+            .await
+            .unwrap();
+        assert_eq!(ids, ["R101"]);
+
+        let analysis = provider
+            .analyze_match(
+                "Backend engineer, Java and Rust",
+                "Build payment APIs in Rust.",
+            )
+            .await
+            .unwrap();
+        assert!(analysis.is_match);
+        assert_eq!(analysis.score, 88);
+
+        // The stub answers this posting with prose instead of JSON.
+        assert!(matches!(
+            provider.extract_posting("Acme | Engineers | Berlin").await,
+            Err(ScannerError::LlmError(_))
+        ));
+    }
+}
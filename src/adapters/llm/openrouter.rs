@@ -1,4 +1,6 @@
-use crate::core::model::Job;
+use crate::adapters::llm::prompts;
+use crate::adapters::llm::schema::response_schema;
+use crate::core::model::ExtractedPosting;
 use crate::core::model::LlmAnalysis;
 use crate::core::model::MatchingJobTitles;
 use crate::domain::llm::AnalysisResult;
@@ -11,99 +13,82 @@ use openrouter_rs::{
     api::chat::{ChatCompletionRequest, Message},
     types::{Choice, ResponseFormat, Role},
 };
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
 
 pub struct OpenRouterProvider {
     pub openrouter_client: OpenRouterClient,
     pub model_name: String,
 }
 
+const OPENROUTER_API: &str = "https://openrouter.ai/api/v1";
+
 impl OpenRouterProvider {
     pub fn new(api_key: String, m_name: String) -> ScannerResult<Self> {
-        match OpenRouterClient::builder().api_key(api_key).build() {
+        Self::with_base_url(api_key, m_name, OPENROUTER_API)
+    }
+
+    /// Talks to an OpenRouter compatible API rooted at `base_url`, e.g. a `ReplayServer`.
+    pub fn with_base_url(api_key: String, m_name: String, base_url: &str) -> ScannerResult<Self> {
+        match OpenRouterClient::builder()
+            .base_url(base_url)
+            .api_key(api_key)
+            .build()
+        {
             Ok(c) => Ok(Self {
                 openrouter_client: c,
                 model_name: m_name,
             }),
             Err(err) => {
-                let err_msg = format!("Cannot build openrouter client: {}", err.to_string());
+                let err_msg = format!("Cannot build openrouter client: {}", err);
                 Err(LlmError(err_msg))
             }
         }
     }
-}
 
-#[async_trait]
-impl LLMProvider for OpenRouterProvider {
-    async fn filter_titles(
+    /// Sends `prompt` with a strict JSON schema generated from `T` and parses the answer.
+    async fn structured_completion<T: JsonSchema + DeserializeOwned>(
         &self,
-        job_list: Vec<Job>,
-        profile_summary: &str,
-    ) -> ScannerResult<Vec<Job>> {
-        let prompt = format!(
-            "You are an expert career coach. Based on this professional summary: '{}', review the following list of job titles.
-            Return ONLY a JSON array of the titles that are a strong potential match.
-            Do not include any other text or explanation. Job Titles: {:?}",
-            profile_summary, job_list
-        );
-
-        let format = ResponseFormat::json_schema(
-            "character_info",
-            true,
-            serde_json::json!({
-              "type": "object",
-              "properties": {
-                "matching_jobs": {
-                  "type": "array",
-                  "properties": {
-                      "id": {
-                        "type": "string",
-                        "description": "job id",
-                      },
-                      "title": {
-                        "type": "string",
-                        "description": "job title",
-                      },
-                      "location": {
-                        "type": "string",
-                        "description": "job location",
-                      },
-                      "description": {
-                        "type": "string",
-                        "description": "job description (optional)",
-                      },
-                      "external_path": {
-                        "type": "string",
-                        "description": "job externalPath",
-                      },
-                      "company_name": {
-                        "type": "string",
-                        "description": "job company name",
-                      },
-                  },
-                }
-              },
-            }),
-        );
+        schema_name: &str,
+        prompt: &str,
+    ) -> ScannerResult<T> {
+        let format =
+            ResponseFormat::json_schema(schema_name, true, response_schema::<T>().to_value());
         let chat_request = ChatCompletionRequest::builder()
             .model(self.model_name.clone())
-            .messages(vec![Message::new(Role::User, &prompt)])
+            .messages(vec![Message::new(Role::User, prompt)])
             .response_format(format)
             .build()?;
         let chat_response = self
             .openrouter_client
             .send_chat_completion(&chat_request)
             .await?;
-        let choice = chat_response.choices.first().unwrap();
-        if let Choice::NonStreaming(non_streaming_choice) = choice {
-            if let Some(content) = non_streaming_choice.message.content.clone() {
-                let response: MatchingJobTitles =
-                    serde_json::from_str(&content).map_err(|e| LlmError(e.to_string()))?;
-                return Ok(response.matching_jobs);
-            } else {
-                return Err(LlmError("something went wrong".to_string()));
-            }
+        match chat_response.choices.first() {
+            Some(Choice::NonStreaming(choice)) => match &choice.message.content {
+                Some(content) => serde_json::from_str(content).map_err(|e| LlmError(e.to_string())),
+                None => Err(LlmError("something went wrong".to_string())),
+            },
+            _ => Err(LlmError("something went wrong".to_string())),
         }
-        Err(LlmError("something went wrong".to_string()))
+    }
+}
+
+#[async_trait]
+impl LLMProvider for OpenRouterProvider {
+    fn model_name(&self) -> &str {
+        &self.model_name
+    }
+
+    async fn filter_titles(
+        &self,
+        title_lines: &[String],
+        profile_summary: &str,
+    ) -> ScannerResult<Vec<String>> {
+        let prompt = prompts::filter_titles(title_lines, profile_summary);
+        let response: MatchingJobTitles = self
+            .structured_completion("matching_job_titles", &prompt)
+            .await?;
+        Ok(response.matching_jobs)
     }
 
     async fn analyze_match(
@@ -111,62 +96,43 @@ impl LLMProvider for OpenRouterProvider {
         resume_text: &str,
         job_description: &str,
     ) -> ScannerResult<AnalysisResult> {
-        let prompt = format!(
-                    "You are an AI hiring assistant. Analyze the following resume and job description.
-                    Provide a JSON response with three keys: 'is_match' (boolean),
-                    'score' (a number from 0 to 100 representing the quality of the match),
-                    and 'reasoning' (a brief, one-paragraph explanation for your decision). Resume: '{}' Job Description: '{}'",
-                    resume_text, job_description
-                );
-        let format = ResponseFormat::json_schema(
-            "character_info",
-            true,
-            serde_json::json!({
-              "type": "object",
-              "properties": {
-                "LlmAnalysis": {
-                  "type": "object",
-                  "properties": {
-                      "is_match": {
-                        "type": "bool",
-                        "description": "Check if the given job description is suitable match for the profile",
-                      },
-                      "score": {
-                        "type": "number",
-                        "description": "score between 0 to 100 on how good is the match",
-                      },
-                      "reasoning": {
-                        "type": "string",
-                        "description": "reasoning behind the match",
-                      }
-                  },
-                }
-              },
-            }),
-        );
-        let chat_request = ChatCompletionRequest::builder()
-            .model(self.model_name.clone())
-            .messages(vec![Message::new(Role::User, &prompt)])
-            .response_format(format)
-            .build()?;
-        let chat_response = self
-            .openrouter_client
-            .send_chat_completion(&chat_request)
-            .await?;
-        let choice = chat_response.choices.first().unwrap();
-        if let Choice::NonStreaming(non_streaming_choice) = choice {
-            if let Some(content) = non_streaming_choice.message.content.clone() {
-                let response: LlmAnalysis =
-                    serde_json::from_str(&content).map_err(|e| LlmError(e.to_string()))?;
-                return Ok(AnalysisResult {
-                    is_match: response.is_match,
-                    score: response.score,
-                    reasoning: response.reasoning,
-                });
-            } else {
-                return Err(LlmError("something went wrong".to_string()));
-            }
-        }
-        Err(LlmError("something went wrong".to_string()))
+        let prompt = prompts::analyze_match(resume_text, job_description);
+        let response: LlmAnalysis = self.structured_completion("llm_analysis", &prompt).await?;
+        Ok(AnalysisResult {
+            is_match: response.is_match,
+            score: response.score,
+            reasoning: response.reasoning,
+        })
+    }
+
+    async fn extract_posting(&self, posting_text: &str) -> ScannerResult<ExtractedPosting> {
+        let prompt = prompts::extract_posting(posting_text);
+        self.structured_completion("extracted_posting", &prompt)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::llm::schema::example_instance;
+
+    /// The schema as it goes over the wire inside `response_format`.
+    fn sent_schema<T: JsonSchema>() -> serde_json::Value {
+        let format = ResponseFormat::json_schema("test", true, response_schema::<T>().to_value());
+        serde_json::to_value(format).unwrap()["json_schema"]["schema"].clone()
+    }
+
+    #[test]
+    fn test_response_schemas_deserialize_into_target_types() {
+        let titles: MatchingJobTitles =
+            serde_json::from_value(example_instance(&sent_schema::<MatchingJobTitles>())).unwrap();
+        assert_eq!(titles.matching_jobs, ["text"]);
+        let analysis: LlmAnalysis =
+            serde_json::from_value(example_instance(&sent_schema::<LlmAnalysis>())).unwrap();
+        assert_eq!(analysis.score, 100);
+        let posting: ExtractedPosting =
+            serde_json::from_value(example_instance(&sent_schema::<ExtractedPosting>())).unwrap();
+        assert_eq!(posting.company, "text");
     }
 }
@@ -0,0 +1,35 @@
+/// Asks for the ids of the title lines (`id: title (location)`) worth a closer look.
+pub fn filter_titles(title_lines: &[String], profile_summary: &str) -> String {
+    format!(
+        "You are an expert career coach. Based on this professional summary: '{}', \
+         review the following jobs, one per line as 'id: title (location)'.\n\
+         Return ONLY the ids of the jobs that are a strong potential match. \
+         Do not include any other text or explanation. Jobs:\n{}",
+        profile_summary,
+        title_lines.join("\n")
+    )
+}
+
+/// Asks for an `LlmAnalysis` of the resume against one job description.
+pub fn analyze_match(resume_text: &str, job_description: &str) -> String {
+    format!(
+        "You are an AI hiring assistant. Analyze the following resume and job description.\n\
+         Provide a JSON response with three keys: 'is_match' (boolean), \
+         'score' (a number from 0 to 100 representing the quality of the match) \
+         and 'reasoning' (a brief, one-paragraph explanation for your decision).\n\
+         Resume: '{}' Job Description: '{}'",
+        resume_text, job_description
+    )
+}
+
+/// Asks for the `ExtractedPosting` facts of a free text posting.
+pub fn extract_posting(posting_text: &str) -> String {
+    format!(
+        "You are reading a job posting written as free text. Provide a JSON response with four keys: \
+         'company' (the hiring company), 'title' (the role, or roles separated by ', '), \
+         'location' (where the job is based) and 'remote_type' (one of 'OnSite', 'Hybrid', 'Remote', \
+         or null when not stated).\n\
+         Posting: '{}'",
+        posting_text
+    )
+}
@@ -0,0 +1,136 @@
+use schemars::generate::SchemaSettings;
+use schemars::{JsonSchema, Schema};
+use serde_json::Value;
+
+/// JSON schema for a structured LLM answer of type `T`, in the subset that both Ollama and
+/// OpenAI style strict structured outputs accept: sub-schemas inlined instead of `$ref`s,
+/// no `format` annotations, and every object closed with all of its properties required
+/// (an `Option` field is still allowed to be `null`).
+pub fn response_schema<T: JsonSchema>() -> Schema {
+    let mut settings = SchemaSettings::draft07();
+    settings.inline_subschemas = true;
+    let mut schema = settings
+        .into_generator()
+        .into_root_schema_for::<T>()
+        .to_value();
+    if let Some(root) = schema.as_object_mut() {
+        root.remove("$schema");
+    }
+    tighten(&mut schema);
+    Schema::try_from(schema).expect("a schema object stays a schema object")
+}
+
+/// Applies the strict-mode rules to `schema` and to the sub-schemas nested in it. Only
+/// schema positions are visited, so a property that happens to be named `format` or
+/// `properties` is left alone.
+fn tighten(schema: &mut Value) {
+    let Value::Object(object) = schema else {
+        return;
+    };
+    object.remove("format");
+    if let Some(Value::Object(properties)) = object.get_mut("properties") {
+        properties.values_mut().for_each(tighten);
+        let required = properties.keys().cloned().map(Value::String).collect();
+        object.insert("required".to_string(), Value::Array(required));
+        object.insert("additionalProperties".to_string(), Value::Bool(false));
+    }
+    match object.get_mut("items") {
+        Some(Value::Array(items)) => items.iter_mut().for_each(tighten),
+        Some(items) => tighten(items),
+        None => {}
+    }
+    for key in ["anyOf", "oneOf", "allOf"] {
+        if let Some(Value::Array(variants)) = object.get_mut(key) {
+            variants.iter_mut().for_each(tighten);
+        }
+    }
+}
+
+/// Builds the smallest value satisfying `schema`, for checking that whatever a model
+/// answers within the schema deserializes into the target type.
+#[cfg(test)]
+pub fn example_instance(schema: &Value) -> Value {
+    if let Some(Value::Array(values)) = schema.get("enum") {
+        return values.first().cloned().unwrap_or(Value::Null);
+    }
+    if let Some(Value::Array(variants)) = schema.get("anyOf").or(schema.get("oneOf")) {
+        return example_instance(&variants[0]);
+    }
+    let kind = match &schema["type"] {
+        Value::Array(kinds) => kinds[0].as_str().unwrap_or_default(),
+        kind => kind.as_str().unwrap_or_default(),
+    };
+    match kind {
+        "object" => Value::Object(
+            schema["properties"]
+                .as_object()
+                .into_iter()
+                .flatten()
+                .map(|(name, property)| (name.clone(), example_instance(property)))
+                .collect(),
+        ),
+        "array" => Value::Array(vec![example_instance(&schema["items"])]),
+        "string" => Value::String("text".to_string()),
+        "integer" | "number" => schema.get("maximum").cloned().unwrap_or(Value::from(0)),
+        "boolean" => Value::Bool(true),
+        _ => Value::Null,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::model::{ExtractedPosting, LlmAnalysis};
+
+    #[test]
+    fn test_response_schema_is_strict() {
+        let schema = response_schema::<LlmAnalysis>().to_value();
+        assert_eq!(schema["additionalProperties"], false);
+        assert_eq!(
+            schema["required"],
+            serde_json::json!(["is_match", "score", "reasoning"])
+        );
+        assert_eq!(schema["properties"]["score"]["minimum"], 0);
+        assert_eq!(schema["properties"]["score"]["maximum"], 100);
+        assert!(schema["properties"]["score"].get("format").is_none());
+
+        let schema = response_schema::<ExtractedPosting>().to_value();
+        assert!(!schema.to_string().contains("$ref"));
+        assert_eq!(schema["required"].as_array().unwrap().len(), 4);
+        let posting: ExtractedPosting = serde_json::from_value(example_instance(&schema)).unwrap();
+        assert_eq!(
+            posting.remote_type,
+            Some(crate::core::model::RemoteType::OnSite)
+        );
+    }
+
+    #[test]
+    fn test_property_names_are_not_treated_as_keywords() {
+        #[derive(JsonSchema)]
+        #[allow(dead_code)]
+        struct Inner {
+            format: String,
+        }
+        #[derive(JsonSchema)]
+        #[allow(dead_code)]
+        struct Tricky {
+            format: String,
+            properties: Vec<Inner>,
+        }
+
+        let schema = response_schema::<Tricky>().to_value();
+        assert_eq!(
+            schema["required"],
+            serde_json::json!(["format", "properties"])
+        );
+        assert_eq!(schema["properties"]["format"]["type"], "string");
+        let properties = &schema["properties"]["properties"];
+        assert!(properties.get("required").is_none());
+        assert!(properties.get("additionalProperties").is_none());
+        assert_eq!(
+            properties["items"]["required"],
+            serde_json::json!(["format"])
+        );
+        assert_eq!(properties["items"]["additionalProperties"], false);
+    }
+}
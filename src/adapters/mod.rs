@@ -1,4 +1,20 @@
+pub mod ashby_client;
+pub mod feed_source;
+pub mod file_source;
+pub mod greenhouse_client;
+pub mod hacker_news_client;
+pub mod http;
+pub mod json_ld_source;
+pub mod lever_client;
 pub mod llm;
 pub mod ollama;
+pub mod oracle_hcm_client;
+#[cfg(any(test, feature = "replay"))]
+pub mod replay;
+pub mod smartrecruiters_client;
+pub mod source_detector;
+pub mod source_router;
 pub mod sqlite_storage;
+pub mod successfactors_client;
+pub mod workable_client;
 pub mod workday_client;
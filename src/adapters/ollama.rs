@@ -1,4 +1,6 @@
-use crate::core::model::{Job, LlmAnalysis, MatchingJobTitles};
+use crate::adapters::llm::prompts;
+use crate::adapters::llm::schema::response_schema;
+use crate::core::model::{ExtractedPosting, LlmAnalysis, MatchingJobTitles};
 use crate::domain::llm::AnalysisResult;
 use crate::domain::llm::LLMProvider;
 use crate::error::ScannerError::LlmError;
@@ -8,6 +10,15 @@ use ollama_rs::Ollama;
 use ollama_rs::generation::completion::request::GenerationRequest;
 use ollama_rs::generation::parameters::{FormatType, JsonStructure};
 use ollama_rs::models::ModelOptions;
+use schemars::JsonSchema;
+use url::Url;
+
+/// Constrains the answer to the JSON schema of `T`, the same one OpenRouter is sent.
+fn structured<T: JsonSchema>() -> FormatType {
+    FormatType::StructuredJson(Box::new(JsonStructure::new_for_schema(
+        response_schema::<T>(),
+    )))
+}
 
 pub struct OllamaProvider {
     ollama: Ollama,
@@ -21,23 +32,30 @@ impl OllamaProvider {
             model,
         }
     }
+
+    /// Talks to the Ollama server at `url` instead of `http://localhost:11434/`.
+    pub fn with_url(model: String, url: Url) -> Self {
+        Self {
+            ollama: Ollama::from_url(url),
+            model,
+        }
+    }
 }
 
 #[async_trait]
 impl LLMProvider for OllamaProvider {
+    fn model_name(&self) -> &str {
+        &self.model
+    }
+
     async fn filter_titles(
         &self,
-        job_list: Vec<Job>,
+        title_lines: &[String],
         profile_summary: &str,
-    ) -> ScannerResult<Vec<Job>> {
-        let prompt = format!(
-            "You are an expert career coach. Based on this professional summary: '{}', review the following list of job titles.
-            Return ONLY a JSON array of the titles that are a strong potential match. Do not include any other text or explanation. Job Titles: {:?}",
-            profile_summary, job_list
-        );
+    ) -> ScannerResult<Vec<String>> {
+        let prompt = prompts::filter_titles(title_lines, profile_summary);
 
-        let format =
-            FormatType::StructuredJson(Box::new(JsonStructure::new::<MatchingJobTitles>()));
+        let format = structured::<MatchingJobTitles>();
         let res = self
             .ollama
             .generate(
@@ -50,7 +68,6 @@ impl LLMProvider for OllamaProvider {
 
         let response: MatchingJobTitles =
             serde_json::from_str(&res.response).map_err(|e| LlmError(e.to_string()))?;
-        println!("filtering complete");
         Ok(response.matching_jobs)
     }
     async fn analyze_match(
@@ -58,14 +75,9 @@ impl LLMProvider for OllamaProvider {
         resume_text: &str,
         job_description: &str,
     ) -> ScannerResult<AnalysisResult> {
-        let prompt = format!(
-                    "You are an AI hiring assistant. Analyze the following resume and job description.
-                    Provide a JSON response with three keys: 'is_match' (boolean), 'score' (a number from 0 to 100 representing the quality of the match),
-                    and 'reasoning' (a brief, one-paragraph explanation for your decision). Resume: '{}' Job Description: '{}'",
-                    resume_text, job_description
-                );
-
-        let format = FormatType::StructuredJson(Box::new(JsonStructure::new::<LlmAnalysis>()));
+        let prompt = prompts::analyze_match(resume_text, job_description);
+
+        let format = structured::<LlmAnalysis>();
         let res = self
             .ollama
             .generate(
@@ -84,4 +96,85 @@ impl LLMProvider for OllamaProvider {
             reasoning: response.reasoning,
         })
     }
+
+    async fn extract_posting(&self, posting_text: &str) -> ScannerResult<ExtractedPosting> {
+        let prompt = prompts::extract_posting(posting_text);
+
+        let format = structured::<ExtractedPosting>();
+        let res = self
+            .ollama
+            .generate(
+                GenerationRequest::new(self.model.clone(), prompt)
+                    .format(format)
+                    .options(ModelOptions::default().num_ctx(10000)),
+            )
+            .await
+            .map_err(|e| LlmError(e.to_string()))?;
+
+        serde_json::from_str(&res.response).map_err(|e| LlmError(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::llm::schema::example_instance;
+    use crate::adapters::replay::ReplayServer;
+    use crate::core::model::RemoteType;
+    use serde::de::DeserializeOwned;
+
+    /// Whatever the model answers within the `format` sent to Ollama must parse as `T`.
+    fn assert_schema_fits<T: JsonSchema + DeserializeOwned>() -> T {
+        let schema = serde_json::to_value(structured::<T>()).unwrap();
+        assert_eq!(schema["additionalProperties"], false);
+        serde_json::from_value(example_instance(&schema)).unwrap()
+    }
+
+    #[test]
+    fn test_response_schemas_deserialize_into_target_types() {
+        assert_eq!(
+            assert_schema_fits::<MatchingJobTitles>().matching_jobs,
+            ["text"]
+        );
+        assert!(assert_schema_fits::<LlmAnalysis>().is_match);
+        assert_eq!(assert_schema_fits::<ExtractedPosting>().company, "text");
+    }
+
+    #[tokio::test]
+    async fn test_structured_answers_from_replayed_server() {
+        let server = ReplayServer::start(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/replay/ollama"),
+            "http://localhost:11434",
+        )
+        .await
+        .unwrap();
+        let provider = OllamaProvider::with_url("qwen3:8b".to_string(), server.url().clone());
+
+        let lines = [
+            "R101: Backend Engineer (Pune)".to_string(),
+            "R102: Marketing Manager (Pune)".to_string(),
+        ];
+        let ids = provider
+            .filter_titles(&lines, "Backend engineer, Java and Rust")
+            .await
+            .unwrap();
+        assert_eq!(ids, ["R101"]);
+
+        let analysis = provider
+            .analyze_match(
+                "Backend engineer, Java and Rust",
+                "Build payment APIs in Rust.",
+            )
+            .await
+            .unwrap();
+        assert!(analysis.is_match);
+        assert_eq!(analysis.score, 79);
+
+        let posting = provider
+            .extract_posting("Acme | Backend Engineer | Berlin | Hybrid")
+            .await
+            .unwrap();
+        assert_eq!(posting.company, "Acme");
+        assert_eq!(posting.remote_type, Some(RemoteType::Hybrid));
+    }
 }
@@ -0,0 +1,499 @@
+use crate::adapters::http::HttpClient;
+use crate::error::{ScannerError, ScannerResult};
+use crate::{
+    core::model::{Company, EmploymentType, Job, RemoteType},
+    domain::job_source::JobSource,
+};
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use url::Url;
+
+const REQUISITIONS_PATH: &str = "/hcmRestApi/resources/latest/recruitingCEJobRequisitions";
+const DETAILS_PATH: &str = "/hcmRestApi/resources/latest/recruitingCEJobRequisitionDetails";
+const FACETS_LIST: &str =
+    "LOCATIONS;WORK_LOCATIONS;WORKPLACE_TYPES;TITLES;CATEGORIES;ORGANIZATIONS;POSTING_DATES";
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[allow(non_snake_case)]
+pub struct OracleSecondaryLocation {
+    pub Name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[allow(non_snake_case)]
+pub struct OracleRequisition {
+    pub Id: String,
+    pub Title: String,
+    /// `YYYY-MM-DD`.
+    pub PostedDate: Option<String>,
+    pub PrimaryLocation: Option<String>,
+    pub PrimaryLocationCountry: Option<String>,
+    pub WorkplaceType: Option<String>,
+    pub JobSchedule: Option<String>,
+    pub JobFamily: Option<String>,
+    #[serde(default)]
+    pub secondaryLocations: Vec<OracleSecondaryLocation>,
+}
+
+/// A selectable facet value; ids are numeric strings such as `300000000469498`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[allow(non_snake_case)]
+pub struct OracleFacetValue {
+    pub Id: serde_json::Value,
+    pub Name: String,
+    pub TotalCount: Option<u32>,
+}
+
+impl OracleFacetValue {
+    fn id(&self) -> String {
+        match &self.Id {
+            serde_json::Value::String(id) => id.clone(),
+            other => other.to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct OracleSearchResult {
+    pub TotalJobsCount: u32,
+    #[serde(default)]
+    pub requisitionList: Vec<OracleRequisition>,
+    /// `locationsFacet`, `workLocationsFacet`, `categoriesFacet`, ... keyed by field name.
+    #[serde(flatten)]
+    pub facets: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct OracleResponse<T> {
+    pub items: Vec<T>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[allow(non_snake_case)]
+pub struct OracleRequisitionDetails {
+    pub ExternalDescriptionStr: Option<String>,
+    pub ExternalResponsibilitiesStr: Option<String>,
+    pub ExternalQualificationsStr: Option<String>,
+}
+
+impl OracleRequisitionDetails {
+    fn full_description(self) -> String {
+        [
+            (None, self.ExternalDescriptionStr),
+            (Some("Responsibilities"), self.ExternalResponsibilitiesStr),
+            (Some("Qualifications"), self.ExternalQualificationsStr),
+        ]
+        .into_iter()
+        .filter_map(|(title, html)| {
+            let html = html.filter(|h| !h.trim().is_empty())?;
+            Some(match title {
+                Some(title) => format!("<h3>{title}</h3>{html}"),
+                None => html,
+            })
+        })
+        .collect()
+    }
+}
+
+impl OracleSearchResult {
+    /// Values of the `{facet}Facet` list, e.g. `locations` -> `locationsFacet`.
+    pub fn facet_values(&self, facet: &str) -> Vec<OracleFacetValue> {
+        self.facets
+            .get(&format!("{facet}Facet"))
+            .and_then(|v| serde_json::from_value(v.clone()).ok())
+            .unwrap_or_default()
+    }
+}
+
+impl OracleRequisition {
+    pub fn into_job(self, company_name: &str, site: &OracleSite) -> Job {
+        let location = self
+            .PrimaryLocation
+            .into_iter()
+            .chain(self.secondaryLocations.into_iter().map(|l| l.Name))
+            .collect::<Vec<_>>()
+            .join("; ");
+        Job {
+            external_path: format!(
+                "{}/hcmUI/CandidateExperience/en/sites/{}/job/{}",
+                site.base, site.site_number, self.Id
+            ),
+            id: self.Id,
+            title: self.Title,
+            location,
+            company_name: company_name.to_string(),
+            posted_at: self
+                .PostedDate
+                .and_then(|d| NaiveDate::parse_from_str(&d, "%Y-%m-%d").ok()),
+            employment_type: self
+                .JobSchedule
+                .as_deref()
+                .and_then(EmploymentType::from_label),
+            remote_type: self
+                .WorkplaceType
+                .as_deref()
+                .and_then(RemoteType::from_label),
+            country: self.PrimaryLocationCountry,
+            department: self.JobFamily,
+            ..Default::default()
+        }
+    }
+}
+
+/// Host and career site a candidate experience URL points at.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OracleSite {
+    pub base: String,
+    pub site_number: String,
+}
+
+/// Parses `https://{host}/hcmUI/CandidateExperience/{lang}/sites/{site}/...`.
+pub fn oracle_site(company_url: &str) -> ScannerResult<OracleSite> {
+    let invalid =
+        |reason: &str| ScannerError::SourceUrlError(company_url.to_string(), reason.to_string());
+
+    let parsed_url = Url::parse(company_url)?;
+    let host = parsed_url.host_str().ok_or_else(|| invalid("missing host"))?;
+    let path_segments: Vec<&str> = parsed_url
+        .path_segments()
+        .map(|segments| segments.filter(|s| !s.is_empty()).collect())
+        .unwrap_or_default();
+    let site_number = path_segments
+        .iter()
+        .position(|s| *s == "sites")
+        .and_then(|idx| path_segments.get(idx + 1))
+        .ok_or_else(|| {
+            invalid("path must look like /hcmUI/CandidateExperience/{lang}/sites/{site}")
+        })?;
+    Ok(OracleSite {
+        base: format!("{}://{}", parsed_url.scheme(), host),
+        site_number: site_number.to_string(),
+    })
+}
+
+/// Resolves configured facet values given as ids or names against the facet list.
+fn resolve_oracle_facet(
+    options: &[OracleFacetValue],
+    facet: &str,
+    value: &str,
+) -> ScannerResult<Vec<String>> {
+    if value.chars().all(|c| c.is_ascii_digit()) || options.iter().any(|o| o.id() == value) {
+        return Ok(vec![value.to_string()]);
+    }
+    let needle = value.to_lowercase();
+    let exact: Vec<String> = options
+        .iter()
+        .filter(|o| o.Name.to_lowercase() == needle)
+        .map(OracleFacetValue::id)
+        .collect();
+    if !exact.is_empty() {
+        return Ok(exact);
+    }
+    let partial: Vec<String> = options
+        .iter()
+        .filter(|o| o.Name.to_lowercase().contains(&needle))
+        .map(OracleFacetValue::id)
+        .collect();
+    if partial.is_empty() {
+        return Err(ScannerError::FacetError(format!(
+            "no `{facet}` facet matches \"{value}\""
+        )));
+    }
+    Ok(partial)
+}
+
+/// Builds the `findReqs` finder expression the requisitions endpoint pages with.
+fn find_reqs(
+    site_number: &str,
+    limit: u32,
+    offset: u32,
+    selected: &BTreeMap<String, Vec<String>>,
+    keyword: &str,
+) -> String {
+    let mut finder = format!(
+        "findReqs;siteNumber={site_number},facetsList={FACETS_LIST},limit={limit},offset={offset},sortBy=POSTING_DATES_DESC"
+    );
+    for (facet, ids) in selected {
+        let mut name = facet.clone();
+        if let Some(first) = name.get_mut(0..1) {
+            first.make_ascii_uppercase();
+        }
+        finder.push_str(&format!(",selected{name}Facet={}", ids.join(";")));
+    }
+    if !keyword.is_empty() {
+        finder.push_str(&format!(",keyword=\"{}\"", keyword.replace('"', "")));
+    }
+    finder
+}
+
+/// Reads Oracle Recruiting Cloud candidate experience sites.
+///
+/// `Company::facets` keys name Oracle facets (`locations`, `workLocations`, `categories`,
+/// `workplaceTypes`, `organizations`, `titles`, `postingDates`) and take ids or names.
+pub struct OracleHcmClient {
+    http: HttpClient,
+    page_size: u32,
+    max_pages: u32,
+}
+
+impl Default for OracleHcmClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OracleHcmClient {
+    pub fn new() -> Self {
+        OracleHcmClient {
+            http: HttpClient::default(),
+            page_size: 25,
+            max_pages: 50,
+        }
+    }
+
+    pub fn with_http_client(mut self, http: HttpClient) -> Self {
+        self.http = http;
+        self
+    }
+
+    async fn search(&self, site: &OracleSite, finder: &str) -> ScannerResult<OracleSearchResult> {
+        let url = format!("{}{REQUISITIONS_PATH}", site.base);
+        let request = self.http.get(&url).query(&[
+            ("onlyData", "true"),
+            (
+                "expand",
+                "requisitionList.secondaryLocations,flexFieldsFacet.values",
+            ),
+            ("finder", finder),
+        ]);
+        let resp = self.http.send(request).await?;
+        let body = resp.json::<OracleResponse<OracleSearchResult>>().await?;
+        Ok(body.items.into_iter().next().unwrap_or_default())
+    }
+
+    async fn selected_facets(
+        &self,
+        company: &Company,
+        site: &OracleSite,
+    ) -> ScannerResult<BTreeMap<String, Vec<String>>> {
+        let needs_lookup = company
+            .facets
+            .values()
+            .flatten()
+            .any(|v| !v.chars().all(|c| c.is_ascii_digit()));
+        let discovery = if needs_lookup {
+            let finder = find_reqs(&site.site_number, 1, 0, &BTreeMap::new(), "");
+            Some(self.search(site, &finder).await?)
+        } else {
+            None
+        };
+
+        let mut selected = BTreeMap::new();
+        for (facet, values) in &company.facets {
+            let options = discovery
+                .as_ref()
+                .map(|d| d.facet_values(facet))
+                .unwrap_or_default();
+            let mut ids: Vec<String> = Vec::new();
+            for value in values.iter().filter(|v| !v.is_empty()) {
+                for id in resolve_oracle_facet(&options, facet, value)? {
+                    if !ids.contains(&id) {
+                        ids.push(id);
+                    }
+                }
+            }
+            if !ids.is_empty() {
+                selected.insert(facet.clone(), ids);
+            }
+        }
+        Ok(selected)
+    }
+}
+
+#[async_trait]
+impl JobSource for OracleHcmClient {
+    async fn get_job_list(&self, company: &Company) -> ScannerResult<Vec<Job>> {
+        let site = oracle_site(&company.url)?;
+        let selected = self.selected_facets(company, &site).await?;
+        let queries: Vec<&str> = if company.search_queries.is_empty() {
+            vec![""]
+        } else {
+            company.search_queries.iter().map(String::as_str).collect()
+        };
+
+        let mut seen = HashSet::new();
+        let mut jobs = Vec::new();
+        for query in queries {
+            let mut offset = 0;
+            for _ in 0..self.max_pages {
+                let finder = find_reqs(&site.site_number, self.page_size, offset, &selected, query);
+                let result = self.search(&site, &finder).await?;
+                let page_len = result.requisitionList.len() as u32;
+                let total = result.TotalJobsCount;
+                jobs.extend(
+                    result
+                        .requisitionList
+                        .into_iter()
+                        .filter(|r| seen.insert(r.Id.clone()))
+                        .map(|r| r.into_job(&company.name, &site)),
+                );
+                offset += page_len;
+                if page_len == 0 || offset >= total {
+                    break;
+                }
+            }
+        }
+        Ok(jobs)
+    }
+
+    async fn get_job_details(&self, job: &Job, company: &Company) -> ScannerResult<Job> {
+        let site = oracle_site(&company.url)?;
+        let url = format!("{}{DETAILS_PATH}", site.base);
+        let finder = format!(
+            "ById;Id=\"{}\",siteNumber={}",
+            job.id, site.site_number
+        );
+        let request = self.http.get(&url).query(&[
+            ("expand", "all"),
+            ("onlyData", "true"),
+            ("finder", finder.as_str()),
+        ]);
+        let resp = self.http.send(request).await?;
+        let body = resp
+            .json::<OracleResponse<OracleRequisitionDetails>>()
+            .await?;
+        Ok(Job {
+            description: body
+                .items
+                .into_iter()
+                .next()
+                .map(OracleRequisitionDetails::full_description),
+            ..job.clone()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SEARCH: &str = r#"
+        {
+            "items": [
+                {
+                    "SearchId": 1,
+                    "TotalJobsCount": 2,
+                    "Offset": 0,
+                    "Limit": 25,
+                    "requisitionList": [
+                        {
+                            "Id": "12345",
+                            "Title": "Java Developer",
+                            "PostedDate": "2024-05-01",
+                            "PrimaryLocation": "Pune, MH, India",
+                            "PrimaryLocationCountry": "IN",
+                            "WorkplaceType": "Hybrid",
+                            "JobSchedule": "Full time",
+                            "JobFamily": "Software Engineering",
+                            "secondaryLocations": [{ "Name": "Mumbai, MH, India" }]
+                        }
+                    ],
+                    "locationsFacet": [
+                        { "Id": 300000000469498, "Name": "India", "TotalCount": 40 },
+                        { "Id": 300000000469499, "Name": "Pune, MH, India", "TotalCount": 12 }
+                    ],
+                    "workplaceTypesFacet": [
+                        { "Id": "ORA_HYBRID", "Name": "Hybrid", "TotalCount": 20 }
+                    ]
+                }
+            ],
+            "count": 1,
+            "hasMore": false
+        }
+    "#;
+
+    #[test]
+    fn test_parse_oracle_site() {
+        let site = oracle_site(
+            "https://eeho.fa.us2.oraclecloud.com/hcmUI/CandidateExperience/en/sites/CX_1/requisitions",
+        )
+        .unwrap();
+        assert_eq!(
+            site,
+            OracleSite {
+                base: "https://eeho.fa.us2.oraclecloud.com".to_string(),
+                site_number: "CX_1".to_string(),
+            }
+        );
+        assert!(oracle_site("https://eeho.fa.us2.oraclecloud.com/hcmUI/").is_err());
+    }
+
+    #[test]
+    fn test_oracle_search_maps_requisitions_and_facets() {
+        let response = serde_json::from_str::<OracleResponse<OracleSearchResult>>(SEARCH).unwrap();
+        let result = response.items.into_iter().next().unwrap();
+        let locations = result.facet_values("locations");
+        assert_eq!(
+            resolve_oracle_facet(&locations, "locations", "india").unwrap(),
+            vec!["300000000469498"]
+        );
+        assert_eq!(
+            resolve_oracle_facet(&locations, "locations", "Pune").unwrap(),
+            vec!["300000000469499"]
+        );
+        assert_eq!(
+            resolve_oracle_facet(&result.facet_values("workplaceTypes"), "workplaceTypes", "Hybrid")
+                .unwrap(),
+            vec!["ORA_HYBRID"]
+        );
+        assert_eq!(
+            resolve_oracle_facet(&result.facet_values("workplaceTypes"), "workplaceTypes", "ORA_HYBRID")
+                .unwrap(),
+            vec!["ORA_HYBRID"]
+        );
+        assert!(resolve_oracle_facet(&locations, "locations", "Berlin").is_err());
+
+        let site = OracleSite {
+            base: "https://eeho.fa.us2.oraclecloud.com".to_string(),
+            site_number: "CX_1".to_string(),
+        };
+        let job = result
+            .requisitionList
+            .into_iter()
+            .next()
+            .unwrap()
+            .into_job("Oracle", &site);
+        assert_eq!(job.location, "Pune, MH, India; Mumbai, MH, India");
+        assert_eq!(job.posted_at, NaiveDate::from_ymd_opt(2024, 5, 1));
+        assert_eq!(job.remote_type, Some(RemoteType::Hybrid));
+        assert_eq!(job.employment_type, Some(EmploymentType::FullTime));
+        assert_eq!(
+            job.apply_url(""),
+            "https://eeho.fa.us2.oraclecloud.com/hcmUI/CandidateExperience/en/sites/CX_1/job/12345"
+        );
+    }
+
+    #[test]
+    fn test_find_reqs_finder() {
+        let selected = [
+            (
+                "locations".to_string(),
+                vec!["300000000469498".to_string(), "300000000469499".to_string()],
+            ),
+            ("workplaceTypes".to_string(), vec!["ORA_HYBRID".to_string()]),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(
+            find_reqs("CX_1", 25, 50, &selected, "rust \"backend\""),
+            format!(
+                "findReqs;siteNumber=CX_1,facetsList={FACETS_LIST},limit=25,offset=50,sortBy=POSTING_DATES_DESC,\
+                 selectedLocationsFacet=300000000469498;300000000469499,\
+                 selectedWorkplaceTypesFacet=ORA_HYBRID,keyword=\"rust backend\""
+            )
+        );
+    }
+}
@@ -0,0 +1,359 @@
+use crate::error::ScannerResult;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+use tracing::warn;
+use url::Url;
+
+/// Set to a non-empty value to forward requests upstream and (re)write fixtures instead of
+/// replaying them.
+pub const RECORD_ENV: &str = "JOB_SCANNER_RECORD";
+
+/// Request headers that belong to the hop between client and stand-in server.
+const HOP_HEADERS: [&str; 5] = [
+    "host",
+    "content-length",
+    "connection",
+    "accept-encoding",
+    "transfer-encoding",
+];
+
+/// One recorded request/response exchange, stored as a JSON file.
+///
+/// JSON bodies are kept as JSON so fixtures stay readable and can be edited by hand; any
+/// other body, including deliberately malformed JSON, is kept as a string.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Fixture {
+    pub method: String,
+    /// Path and query of the request, e.g. `/wday/cxs/acme/External/jobs`.
+    pub path: String,
+    #[serde(default)]
+    pub request_body: Option<Value>,
+    pub status: u16,
+    #[serde(default)]
+    pub content_type: Option<String>,
+    pub response: Value,
+}
+
+impl Fixture {
+    fn matches(&self, method: &str, path: &str, body: &Option<Value>) -> bool {
+        self.method == method && self.path == path && &self.request_body == body
+    }
+
+    /// Stable, readable file name: method, sanitized path and a hash of the request.
+    fn file_name(&self) -> String {
+        let slug: String = self
+            .path
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect::<String>()
+            .trim_matches('_')
+            .chars()
+            .take(60)
+            .collect();
+        let body = self
+            .request_body
+            .as_ref()
+            .map(Value::to_string)
+            .unwrap_or_default();
+        let hash = fnv1a(format!("{} {} {body}", self.method, self.path).as_bytes());
+        format!("{}_{slug}_{hash:016x}.json", self.method)
+    }
+
+    fn response_bytes(&self) -> Vec<u8> {
+        match &self.response {
+            Value::String(raw) => raw.clone().into_bytes(),
+            json => json.to_string().into_bytes(),
+        }
+    }
+}
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &b| {
+        (hash ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Bodies are compared as JSON when they parse, so key order and whitespace do not matter.
+fn body_value(body: &[u8]) -> Option<Value> {
+    if body.is_empty() {
+        return None;
+    }
+    serde_json::from_slice(body)
+        .ok()
+        .or_else(|| Some(Value::String(String::from_utf8_lossy(body).into_owned())))
+}
+
+/// Reads every `*.json` fixture in `dir`; a missing directory holds no fixtures.
+pub fn load_fixtures(dir: &Path) -> ScannerResult<Vec<Fixture>> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Ok(Vec::new());
+    };
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| p.extension().is_some_and(|e| e == "json"))
+        .collect();
+    paths.sort();
+    paths
+        .iter()
+        .map(|p| Ok(serde_json::from_str(&std::fs::read_to_string(p)?)?))
+        .collect()
+}
+
+enum Mode {
+    Replay(Vec<Fixture>),
+    Record { upstream: Url, client: Client },
+}
+
+struct State {
+    dir: PathBuf,
+    mode: Mode,
+}
+
+struct Request {
+    method: String,
+    path: String,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+struct Reply {
+    status: u16,
+    content_type: Option<String>,
+    body: Vec<u8>,
+}
+
+impl State {
+    async fn reply(&self, request: Request) -> Reply {
+        let body = body_value(&request.body);
+        match &self.mode {
+            Mode::Replay(fixtures) => {
+                match fixtures
+                    .iter()
+                    .find(|f| f.matches(&request.method, &request.path, &body))
+                {
+                    Some(fixture) => Reply {
+                        status: fixture.status,
+                        content_type: fixture.content_type.clone(),
+                        body: fixture.response_bytes(),
+                    },
+                    None => {
+                        let body = body.map(|b| b.to_string()).unwrap_or_default();
+                        let message = format!(
+                            "no fixture in {} for {} {} {body}",
+                            self.dir.display(),
+                            request.method,
+                            request.path
+                        );
+                        warn!("{message}");
+                        Reply {
+                            status: 404,
+                            content_type: Some("text/plain".to_string()),
+                            body: message.into_bytes(),
+                        }
+                    }
+                }
+            }
+            Mode::Record { upstream, client } => match self.record(upstream, client, request).await
+            {
+                Ok(reply) => reply,
+                Err(e) => Reply {
+                    status: 502,
+                    content_type: Some("text/plain".to_string()),
+                    body: format!("recording failed: {e}").into_bytes(),
+                },
+            },
+        }
+    }
+
+    async fn record(
+        &self,
+        upstream: &Url,
+        client: &Client,
+        request: Request,
+    ) -> ScannerResult<Reply> {
+        let url = upstream.join(&request.path)?;
+        let method =
+            reqwest::Method::from_bytes(request.method.as_bytes()).unwrap_or(reqwest::Method::GET);
+        let mut forward = client.request(method, url);
+        for (name, value) in &request.headers {
+            if !HOP_HEADERS.contains(&name.to_ascii_lowercase().as_str()) {
+                forward = forward.header(name, value);
+            }
+        }
+        let resp = forward.body(request.body.clone()).send().await?;
+        let status = resp.status().as_u16();
+        let content_type = resp
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        let bytes = resp.bytes().await?.to_vec();
+
+        let response = serde_json::from_slice(&bytes)
+            .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(&bytes).into_owned()));
+        let fixture = Fixture {
+            method: request.method,
+            path: request.path,
+            request_body: body_value(&request.body),
+            status,
+            content_type: content_type.clone(),
+            response,
+        };
+        std::fs::create_dir_all(&self.dir)?;
+        std::fs::write(
+            self.dir.join(fixture.file_name()),
+            serde_json::to_string_pretty(&fixture)?,
+        )?;
+        Ok(Reply {
+            status,
+            content_type,
+            body: bytes,
+        })
+    }
+}
+
+/// A local stand-in for one upstream origin. Point a client at `url()` (see
+/// `HttpClient::with_base_url`) and it answers from the fixtures in `dir`, or, when
+/// `JOB_SCANNER_RECORD` is set, forwards to `upstream` and records what comes back.
+pub struct ReplayServer {
+    url: Url,
+    task: JoinHandle<()>,
+}
+
+impl ReplayServer {
+    pub async fn start(dir: impl Into<PathBuf>, upstream: &str) -> ScannerResult<Self> {
+        let recording = std::env::var(RECORD_ENV).is_ok_and(|v| !v.is_empty());
+        let dir = dir.into();
+        let mode = if recording {
+            Mode::Record {
+                upstream: Url::parse(upstream)?,
+                client: Client::new(),
+            }
+        } else {
+            Mode::Replay(load_fixtures(&dir)?)
+        };
+        Self::serve(State { dir, mode }).await
+    }
+
+    async fn serve(state: State) -> ScannerResult<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = Url::parse(&format!("http://{}/", listener.local_addr()?))?;
+        let state = Arc::new(state);
+        let task = tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let state = state.clone();
+                tokio::spawn(async move {
+                    if let Err(e) = handle(stream, &state).await {
+                        warn!("replay server connection failed: {e}");
+                    }
+                });
+            }
+        });
+        Ok(ReplayServer { url, task })
+    }
+
+    /// Base URL of the server, `http://127.0.0.1:{port}/`.
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+}
+
+impl Drop for ReplayServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Serves one HTTP/1.1 request with a `Content-Length` body, then closes the connection.
+async fn handle(mut stream: TcpStream, state: &State) -> std::io::Result<()> {
+    let mut buf = Vec::new();
+    let header_end = loop {
+        let mut chunk = [0u8; 8192];
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 {
+            return Ok(());
+        }
+        buf.extend_from_slice(&chunk[..n]);
+        if let Some(end) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            break end;
+        }
+    };
+    let head = String::from_utf8_lossy(&buf[..header_end]).into_owned();
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next().unwrap_or_default().split(' ');
+    let method = request_line.next().unwrap_or_default().to_string();
+    let path = request_line.next().unwrap_or("/").to_string();
+    let headers: Vec<(String, String)> = lines
+        .filter_map(|l| l.split_once(':'))
+        .map(|(k, v)| (k.trim().to_string(), v.trim().to_string()))
+        .collect();
+    let content_length = headers
+        .iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, v)| v.parse::<usize>().ok())
+        .unwrap_or(0);
+    let mut body = buf[header_end + 4..].to_vec();
+    while body.len() < content_length {
+        let mut chunk = vec![0u8; content_length - body.len()];
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 {
+            break;
+        }
+        body.extend_from_slice(&chunk[..n]);
+    }
+
+    let reply = state
+        .reply(Request {
+            method,
+            path,
+            headers,
+            body,
+        })
+        .await;
+    let mut head = format!(
+        "HTTP/1.1 {} {}\r\nContent-Length: {}\r\nConnection: close\r\n",
+        reply.status,
+        reqwest::StatusCode::from_u16(reply.status)
+            .ok()
+            .and_then(|s| s.canonical_reason())
+            .unwrap_or(""),
+        reply.body.len()
+    );
+    if let Some(content_type) = &reply.content_type {
+        head.push_str(&format!("Content-Type: {content_type}\r\n"));
+    }
+    head.push_str("\r\n");
+    stream.write_all(head.as_bytes()).await?;
+    stream.write_all(&reply.body).await?;
+    stream.shutdown().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixture_matching_ignores_json_formatting() {
+        let fixture = Fixture {
+            method: "POST".to_string(),
+            path: "/jobs?x=1".to_string(),
+            request_body: Some(serde_json::json!({ "limit": 20, "offset": 0 })),
+            status: 200,
+            content_type: Some("application/json".to_string()),
+            response: Value::String("{ not json".to_string()),
+        };
+        let body = body_value(br#"{"offset":0,  "limit":20}"#);
+        assert!(fixture.matches("POST", "/jobs?x=1", &body));
+        assert!(!fixture.matches("POST", "/jobs", &body));
+        assert!(!fixture.matches("POST", "/jobs?x=1", &None));
+        assert_eq!(fixture.response_bytes(), b"{ not json");
+        assert!(fixture.file_name().starts_with("POST_jobs_x_1_"));
+        assert_eq!(fixture.file_name(), fixture.clone().file_name());
+    }
+}
@@ -0,0 +1,304 @@
+use crate::adapters::http::HttpClient;
+use crate::error::{ScannerError, ScannerResult};
+use crate::{
+    core::model::{Company, EmploymentType, Job, RemoteType},
+    domain::job_source::JobSource,
+};
+use async_trait::async_trait;
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+const API_BASE: &str = "https://api.smartrecruiters.com/v1/companies";
+const PAGE_SIZE: usize = 100;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SmartRecruitersLabel {
+    pub label: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct SmartRecruitersLocation {
+    pub city: Option<String>,
+    pub region: Option<String>,
+    /// ISO country code, lowercase.
+    pub country: Option<String>,
+    pub fullLocation: Option<String>,
+    #[serde(default)]
+    pub remote: bool,
+    #[serde(default)]
+    pub hybrid: bool,
+}
+
+impl SmartRecruitersLocation {
+    fn describe(&self) -> String {
+        if let Some(full) = &self.fullLocation {
+            return full.clone();
+        }
+        [&self.city, &self.region, &self.country]
+            .into_iter()
+            .flatten()
+            .cloned()
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[allow(non_snake_case)]
+pub struct SmartRecruitersPosting {
+    pub id: String,
+    pub name: String,
+    pub refNumber: Option<String>,
+    pub releasedDate: Option<String>,
+    #[serde(default)]
+    pub location: SmartRecruitersLocation,
+    pub department: Option<SmartRecruitersLabel>,
+    pub typeOfEmployment: Option<SmartRecruitersLabel>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[allow(non_snake_case)]
+pub struct SmartRecruitersPage {
+    pub totalFound: usize,
+    pub content: Vec<SmartRecruitersPosting>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SmartRecruitersSection {
+    pub title: Option<String>,
+    pub text: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[allow(non_snake_case)]
+pub struct SmartRecruitersSections {
+    pub companyDescription: Option<SmartRecruitersSection>,
+    pub jobDescription: Option<SmartRecruitersSection>,
+    pub qualifications: Option<SmartRecruitersSection>,
+    pub additionalInformation: Option<SmartRecruitersSection>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SmartRecruitersJobAd {
+    pub sections: SmartRecruitersSections,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[allow(non_snake_case)]
+pub struct SmartRecruitersPostingDetails {
+    pub jobAd: SmartRecruitersJobAd,
+}
+
+impl SmartRecruitersPostingDetails {
+    /// Joins the job ad sections (without the generic company blurb) into one HTML document.
+    fn description(&self) -> String {
+        let sections = &self.jobAd.sections;
+        [
+            &sections.jobDescription,
+            &sections.qualifications,
+            &sections.additionalInformation,
+        ]
+        .into_iter()
+        .flatten()
+        .map(|section| {
+            let title = section
+                .title
+                .as_deref()
+                .map(|t| format!("<h3>{t}</h3>"))
+                .unwrap_or_default();
+            format!("{title}{}", section.text.as_deref().unwrap_or_default())
+        })
+        .collect()
+    }
+}
+
+impl SmartRecruitersPosting {
+    pub fn into_job(self, company_name: &str, company_id: &str) -> Job {
+        let remote_type = if self.location.remote {
+            Some(RemoteType::Remote)
+        } else if self.location.hybrid {
+            Some(RemoteType::Hybrid)
+        } else {
+            None
+        };
+        Job {
+            external_path: format!("https://jobs.smartrecruiters.com/{company_id}/{}", self.id),
+            id: self.id,
+            title: self.name,
+            location: self.location.describe(),
+            company_name: company_name.to_string(),
+            posted_at: self
+                .releasedDate
+                .and_then(|d| DateTime::parse_from_rfc3339(&d).ok())
+                .map(|d| d.date_naive()),
+            requisition_id: self.refNumber,
+            employment_type: self
+                .typeOfEmployment
+                .and_then(|t| EmploymentType::from_label(&t.label)),
+            remote_type,
+            country: self.location.country.map(|c| c.to_uppercase()),
+            department: self.department.map(|d| d.label),
+            ..Default::default()
+        }
+    }
+}
+
+/// Extracts the company identifier from `careers.smartrecruiters.com/{company}`,
+/// `jobs.smartrecruiters.com/{company}` or the postings API URL.
+pub fn smartrecruiters_company_id(company_url: &str) -> ScannerResult<String> {
+    let invalid =
+        |reason: &str| ScannerError::SourceUrlError(company_url.to_string(), reason.to_string());
+
+    let parsed_url = Url::parse(company_url)?;
+    let domain = parsed_url.domain().ok_or_else(|| invalid("missing host"))?;
+    if !domain.ends_with("smartrecruiters.com") {
+        return Err(invalid("host is not a smartrecruiters.com domain"));
+    }
+    let path_segments: Vec<&str> = parsed_url
+        .path_segments()
+        .map(|segments| segments.filter(|s| !s.is_empty()).collect())
+        .unwrap_or_default();
+    match path_segments.as_slice() {
+        ["v1", "companies", company, ..] => Ok(company.to_string()),
+        ["v1", ..] => Err(invalid("API path must look like /v1/companies/{company}")),
+        [company, ..] => Ok(company.to_string()),
+        [] => Err(invalid("missing company identifier in path")),
+    }
+}
+
+/// Reads a company's public SmartRecruiters postings.
+pub struct SmartRecruitersClient {
+    http: HttpClient,
+}
+
+impl Default for SmartRecruitersClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SmartRecruitersClient {
+    pub fn new() -> Self {
+        SmartRecruitersClient {
+            http: HttpClient::default(),
+        }
+    }
+
+    pub fn with_http_client(mut self, http: HttpClient) -> Self {
+        self.http = http;
+        self
+    }
+}
+
+#[async_trait]
+impl JobSource for SmartRecruitersClient {
+    async fn get_job_list(&self, company: &Company) -> ScannerResult<Vec<Job>> {
+        let company_id = smartrecruiters_company_id(&company.url)?;
+        let mut jobs = Vec::new();
+        loop {
+            let url = format!(
+                "{API_BASE}/{company_id}/postings?limit={PAGE_SIZE}&offset={}",
+                jobs.len()
+            );
+            let resp = self.http.send(self.http.get(&url)).await?;
+            let page = resp.json::<SmartRecruitersPage>().await?;
+            let page_len = page.content.len();
+            jobs.extend(
+                page.content
+                    .into_iter()
+                    .map(|p| p.into_job(&company.name, &company_id)),
+            );
+            if page_len == 0 || jobs.len() >= page.totalFound {
+                break;
+            }
+        }
+        Ok(jobs)
+    }
+
+    async fn get_job_details(&self, job: &Job, company: &Company) -> ScannerResult<Job> {
+        let company_id = smartrecruiters_company_id(&company.url)?;
+        let url = format!("{API_BASE}/{company_id}/postings/{}", job.id);
+        let resp = self.http.send(self.http.get(&url)).await?;
+        let details = resp.json::<SmartRecruitersPostingDetails>().await?;
+        Ok(Job {
+            description: Some(details.description()),
+            ..job.clone()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_parse_smartrecruiters_company_id() {
+        for url in [
+            "https://careers.smartrecruiters.com/Acme1",
+            "https://jobs.smartrecruiters.com/Acme1/743999999999999-backend-engineer",
+            "https://api.smartrecruiters.com/v1/companies/Acme1/postings",
+        ] {
+            assert_eq!(smartrecruiters_company_id(url).unwrap(), "Acme1", "{url}");
+        }
+        assert!(smartrecruiters_company_id("https://acme.com/Acme1").is_err());
+    }
+
+    #[test]
+    fn test_smartrecruiters_posting_and_details() {
+        let page = r#"
+            {
+                "offset": 0,
+                "limit": 100,
+                "totalFound": 1,
+                "content": [
+                    {
+                        "id": "743999999999999",
+                        "name": "Backend Engineer",
+                        "refNumber": "REF123",
+                        "releasedDate": "2024-05-01T10:00:00.000Z",
+                        "location": { "city": "Warsaw", "country": "pl", "remote": false, "hybrid": true },
+                        "department": { "id": "1", "label": "Engineering" },
+                        "typeOfEmployment": { "id": "permanent", "label": "Full-time" }
+                    }
+                ]
+            }
+        "#;
+        let page = serde_json::from_str::<SmartRecruitersPage>(page).unwrap();
+        let job = page
+            .content
+            .into_iter()
+            .next()
+            .unwrap()
+            .into_job("Acme", "Acme1");
+        assert_eq!(job.location, "Warsaw, pl");
+        assert_eq!(job.country.as_deref(), Some("PL"));
+        assert_eq!(job.remote_type, Some(RemoteType::Hybrid));
+        assert_eq!(job.employment_type, Some(EmploymentType::FullTime));
+        assert_eq!(job.posted_at, NaiveDate::from_ymd_opt(2024, 5, 1));
+        assert_eq!(
+            job.apply_url(""),
+            "https://jobs.smartrecruiters.com/Acme1/743999999999999"
+        );
+
+        let details = r#"
+            {
+                "id": "743999999999999",
+                "jobAd": {
+                    "sections": {
+                        "companyDescription": { "title": "Company Description", "text": "<p>We are Acme.</p>" },
+                        "jobDescription": { "title": "Job Description", "text": "<p>Build APIs.</p>" },
+                        "qualifications": { "title": "Qualifications", "text": "<ul><li>Rust</li></ul>" }
+                    }
+                }
+            }
+        "#;
+        let details = serde_json::from_str::<SmartRecruitersPostingDetails>(details).unwrap();
+        assert_eq!(
+            details.description(),
+            "<h3>Job Description</h3><p>Build APIs.</p><h3>Qualifications</h3><ul><li>Rust</li></ul>"
+        );
+    }
+}
@@ -0,0 +1,399 @@
+use crate::adapters::ashby_client::ashby_board_name;
+use crate::adapters::greenhouse_client::greenhouse_board_token;
+use crate::adapters::http::HttpClient;
+use crate::adapters::json_ld_source::{DEFAULT_LINK_SELECTOR, parse_job_postings, posting_links};
+use crate::adapters::lever_client::lever_api_url;
+use crate::adapters::oracle_hcm_client::oracle_site;
+use crate::adapters::smartrecruiters_client::smartrecruiters_company_id;
+use crate::adapters::workable_client::workable_account_slug;
+use crate::adapters::workday_client::workday_to_api_url;
+use crate::core::model::{Company, SourceKind};
+use crate::error::{ScannerError, ScannerResult};
+use scraper::{Html, Selector};
+use serde_json::Value;
+use url::Url;
+
+/// Host labels that say "careers site" rather than name the company.
+const GENERIC_LABELS: [&str; 8] = [
+    "www", "careers", "career", "jobs", "job", "join", "work", "apply",
+];
+
+/// Linked posting pages checked for JSON-LD before giving up on a self-hosted site.
+const MAX_PROBED_LINKS: usize = 3;
+
+/// A careers page recognised as one of the supported sources.
+#[derive(Debug, Clone)]
+pub struct Detection {
+    /// Ready to paste into `data/companies.json`; `name` is the board identifier until the
+    /// caller sets a better one.
+    pub company: Company,
+    /// What gave the source away, e.g. `page embeds https://boards.greenhouse.io/embed/...`.
+    pub evidence: String,
+}
+
+fn company(name: &str, url: String, source: SourceKind) -> Company {
+    Company {
+        name: name.to_string(),
+        url,
+        source,
+        ..Default::default()
+    }
+}
+
+/// Recognises a source from a URL alone and returns its canonical careers URL, e.g. a
+/// Workday job link becomes the career site home page.
+pub fn fingerprint_url(url: &Url) -> Option<Company> {
+    let host = url.host_str()?;
+    let raw = url.as_str();
+    if host.ends_with(".myworkdayjobs.com") || host.ends_with(".myworkdaysite.com") {
+        let api_url = Url::parse(&workday_to_api_url(raw).ok()?).ok()?;
+        let segments: Vec<&str> = api_url.path_segments()?.collect();
+        let [_, _, tenant, site] = segments.as_slice() else {
+            return None;
+        };
+        let url = if host.ends_with(".myworkdayjobs.com") {
+            format!("https://{host}/{site}")
+        } else {
+            format!("https://{host}/recruiting/{tenant}/{site}")
+        };
+        return Some(company(tenant, url, SourceKind::Workday));
+    }
+    if host.ends_with("greenhouse.io") && host.contains("boards") {
+        let token = greenhouse_board_token(raw).ok()?;
+        let url = format!("https://boards.greenhouse.io/{token}");
+        return Some(company(&token, url, SourceKind::Greenhouse));
+    }
+    if host.ends_with("lever.co") {
+        let api_url = lever_api_url(raw).ok()?;
+        let (api_host, name) = api_url
+            .strip_prefix("https://")?
+            .split_once("/v0/postings/")?;
+        let url = format!("https://{}/{name}", api_host.replacen("api.", "jobs.", 1));
+        return Some(company(name, url, SourceKind::Lever));
+    }
+    if host.ends_with("ashbyhq.com") {
+        let board = ashby_board_name(raw).ok()?;
+        let url = format!("https://jobs.ashbyhq.com/{board}");
+        return Some(company(&board, url, SourceKind::Ashby));
+    }
+    if host.ends_with("smartrecruiters.com") && !host.starts_with("www.") {
+        let id = smartrecruiters_company_id(raw).ok()?;
+        let url = format!("https://careers.smartrecruiters.com/{id}");
+        return Some(company(&id, url, SourceKind::SmartRecruiters));
+    }
+    if host.ends_with("workable.com") {
+        let slug = workable_account_slug(raw).ok()?;
+        let url = format!("https://apply.workable.com/{slug}");
+        return Some(company(&slug, url, SourceKind::Workable));
+    }
+    if host.ends_with(".oraclecloud.com") && url.path().contains("/CandidateExperience/") {
+        let site = oracle_site(raw).ok()?;
+        let url = format!(
+            "{}/hcmUI/CandidateExperience/en/sites/{}",
+            site.base, site.site_number
+        );
+        let name = host.split('.').next().unwrap_or(host);
+        return Some(company(name, url, SourceKind::OracleHcm));
+    }
+    if let Some(subdomain) = host.strip_suffix(".icims.com") {
+        // iCIMS has no public API, but its posting pages carry schema.org JobPosting data.
+        let name = subdomain
+            .trim_start_matches("careers-")
+            .trim_start_matches("jobs-");
+        let url = format!("https://{host}/jobs/search?ss=1");
+        return Some(Company {
+            link_selector: Some("a.iCIMS_Anchor".to_string()),
+            ..company(name, url, SourceKind::JsonLd)
+        });
+    }
+    None
+}
+
+/// Looks for a supported job board embedded in, or linked from, a careers page. Iframes and
+/// scripts are checked before plain links, which are more likely to point elsewhere.
+pub fn fingerprint_page(html: &str, page_url: &Url) -> Option<(Company, Url)> {
+    let document = Html::parse_document(html);
+    [("iframe[src], script[src]", "src"), ("a[href]", "href")]
+        .into_iter()
+        .find_map(|(css, attr)| {
+            let selector = Selector::parse(css).expect("static selector");
+            document
+                .select(&selector)
+                .filter_map(|element| page_url.join(element.value().attr(attr)?).ok())
+                .find_map(|link| Some((fingerprint_url(&link)?, link)))
+        })
+}
+
+/// Guesses the board identifier a company would pick from its own careers host, e.g.
+/// `careers.acme.com` gives `acme`.
+pub fn board_slug(url: &Url) -> Option<String> {
+    let labels: Vec<&str> = url.domain()?.split('.').collect();
+    labels[..labels.len() - 1]
+        .iter()
+        .find(|label| !GENERIC_LABELS.contains(label))
+        .map(|label| label.to_string())
+}
+
+/// Public job board APIs that answer for any identifier: (name, URL template, array of jobs
+/// in the response, `""` for a top level array).
+const PROBES: [(&str, &str, &str); 4] = [
+    (
+        "Greenhouse",
+        "https://boards-api.greenhouse.io/v1/boards/{slug}/jobs",
+        "jobs",
+    ),
+    (
+        "Lever",
+        "https://api.lever.co/v0/postings/{slug}?limit=1&mode=json",
+        "",
+    ),
+    (
+        "Ashby",
+        "https://api.ashbyhq.com/posting-api/job-board/{slug}",
+        "jobs",
+    ),
+    (
+        "SmartRecruiters",
+        "https://api.smartrecruiters.com/v1/companies/{slug}/postings?limit=1",
+        "content",
+    ),
+];
+
+/// Works out which source a careers URL belongs to: its host and redirects first, then job
+/// boards embedded in the page, schema.org `JobPosting` data, and finally the public job
+/// board APIs probed with an identifier guessed from the host.
+pub struct SourceDetector {
+    http: HttpClient,
+}
+
+impl Default for SourceDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SourceDetector {
+    pub fn new() -> Self {
+        SourceDetector {
+            http: HttpClient::default(),
+        }
+    }
+
+    pub fn with_http_client(mut self, http: HttpClient) -> Self {
+        self.http = http;
+        self
+    }
+
+    pub async fn detect(&self, careers_url: &str) -> ScannerResult<Detection> {
+        let url = Url::parse(careers_url)?;
+        if let Some(company) = fingerprint_url(&url) {
+            return Ok(Detection {
+                company,
+                evidence: format!("host {}", url.host_str().unwrap_or_default()),
+            });
+        }
+
+        let resp = self.http.send(self.http.get(url.as_str())).await?;
+        let page_url = resp.url().clone();
+        if let Some(company) = fingerprint_url(&page_url) {
+            return Ok(Detection {
+                company,
+                evidence: format!("redirected to {page_url}"),
+            });
+        }
+        let html = resp.text().await?;
+        if let Some((company, link)) = fingerprint_page(&html, &page_url) {
+            return Ok(Detection {
+                company,
+                evidence: format!("page embeds {link}"),
+            });
+        }
+
+        let name = board_slug(&url).unwrap_or_default();
+        if !parse_job_postings(&html, page_url.as_str(), &name).is_empty() {
+            return Ok(Detection {
+                company: company(&name, page_url.to_string(), SourceKind::JsonLd),
+                evidence: "page carries schema.org JobPosting data".to_string(),
+            });
+        }
+        if let Some(detection) = self.probe_apis(&name).await {
+            return Ok(detection);
+        }
+        let links = posting_links(&html, &page_url, DEFAULT_LINK_SELECTOR)?;
+        for link in links.into_iter().take(MAX_PROBED_LINKS) {
+            let Ok(resp) = self.http.send(self.http.get(link.as_str())).await else {
+                continue;
+            };
+            let page = resp.text().await.unwrap_or_default();
+            if !parse_job_postings(&page, link.as_str(), &name).is_empty() {
+                return Ok(Detection {
+                    company: company(&name, page_url.to_string(), SourceKind::JsonLd),
+                    evidence: format!("linked posting {link} carries schema.org JobPosting data"),
+                });
+            }
+        }
+        Err(ScannerError::SourceUrlError(
+            careers_url.to_string(),
+            "no supported job source detected".to_string(),
+        ))
+    }
+
+    /// Asks each public job board API for `slug`; boards without open jobs do not count, as
+    /// some APIs answer for any identifier.
+    async fn probe_apis(&self, slug: &str) -> Option<Detection> {
+        if slug.is_empty() {
+            return None;
+        }
+        for (board, template, jobs_key) in PROBES {
+            let api_url = template.replace("{slug}", slug);
+            let Ok(resp) = self.http.send(self.http.get(&api_url)).await else {
+                continue;
+            };
+            let Ok(body) = resp.json::<Value>().await else {
+                continue;
+            };
+            let jobs = if jobs_key.is_empty() {
+                &body
+            } else {
+                &body[jobs_key]
+            };
+            if jobs.as_array().is_some_and(|jobs| !jobs.is_empty()) {
+                let company = fingerprint_url(&Url::parse(&api_url).ok()?)?;
+                return Some(Detection {
+                    company,
+                    evidence: format!("{board} API lists jobs for `{slug}`"),
+                });
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::http::HttpConfig;
+    use crate::adapters::replay::ReplayServer;
+
+    fn detected(url: &str) -> Option<(SourceKind, String, String)> {
+        fingerprint_url(&Url::parse(url).unwrap()).map(|c| (c.source, c.url, c.name))
+    }
+
+    #[test]
+    fn test_fingerprint_url() {
+        let cases = [
+            (
+                "https://acme.wd1.myworkdayjobs.com/en-US/External/job/Pune/Backend-Engineer_R101",
+                SourceKind::Workday,
+                "https://acme.wd1.myworkdayjobs.com/External",
+                "acme",
+            ),
+            (
+                "https://wd3.myworkdaysite.com/recruiting/acme/Careers",
+                SourceKind::Workday,
+                "https://wd3.myworkdaysite.com/recruiting/acme/Careers",
+                "acme",
+            ),
+            (
+                "https://boards.greenhouse.io/embed/job_board/js?for=acme",
+                SourceKind::Greenhouse,
+                "https://boards.greenhouse.io/acme",
+                "acme",
+            ),
+            (
+                "https://jobs.eu.lever.co/acme/1234-abcd",
+                SourceKind::Lever,
+                "https://jobs.eu.lever.co/acme",
+                "acme",
+            ),
+            (
+                "https://jobs.ashbyhq.com/acme/embed",
+                SourceKind::Ashby,
+                "https://jobs.ashbyhq.com/acme",
+                "acme",
+            ),
+            (
+                "https://jobs.smartrecruiters.com/Acme1/743999",
+                SourceKind::SmartRecruiters,
+                "https://careers.smartrecruiters.com/Acme1",
+                "Acme1",
+            ),
+            (
+                "https://acme.workable.com/",
+                SourceKind::Workable,
+                "https://apply.workable.com/acme",
+                "acme",
+            ),
+            (
+                "https://careers-acme.icims.com/jobs/1234/backend-engineer/job",
+                SourceKind::JsonLd,
+                "https://careers-acme.icims.com/jobs/search?ss=1",
+                "acme",
+            ),
+        ];
+        for (url, source, canonical, name) in cases {
+            assert_eq!(
+                detected(url),
+                Some((source, canonical.to_string(), name.to_string())),
+                "{url}"
+            );
+        }
+        assert_eq!(detected("https://www.greenhouse.io/customers"), None);
+        assert_eq!(detected("https://acme.com/careers"), None);
+    }
+
+    #[test]
+    fn test_fingerprint_page_prefers_embeds_over_links() {
+        let html = r#"
+            <a href="https://jobs.lever.co/partner">Our partner is hiring</a>
+            <div id="grnhse_app"></div>
+            <script src="https://boards.greenhouse.io/embed/job_board/js?for=acme"></script>
+        "#;
+        let page = Url::parse("https://acme.com/careers").unwrap();
+        let (company, link) = fingerprint_page(html, &page).unwrap();
+        assert_eq!(company.source, SourceKind::Greenhouse);
+        assert_eq!(company.url, "https://boards.greenhouse.io/acme");
+        assert_eq!(link.host_str(), Some("boards.greenhouse.io"));
+        assert!(fingerprint_page("<a href=\"/about\">About</a>", &page).is_none());
+    }
+
+    #[test]
+    fn test_board_slug() {
+        let slug = |url: &str| board_slug(&Url::parse(url).unwrap());
+        assert_eq!(slug("https://careers.acme.com/"), Some("acme".to_string()));
+        assert_eq!(
+            slug("https://www.acme.co.uk/jobs"),
+            Some("acme".to_string())
+        );
+        assert_eq!(slug("https://jobs.com/"), None);
+        assert_eq!(slug("http://127.0.0.1:8080/"), None);
+    }
+
+    #[tokio::test]
+    async fn test_detect_probes_job_board_apis() {
+        let server = ReplayServer::start(
+            concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/tests/fixtures/replay/source_detector"
+            ),
+            "https://careers.acme.com",
+        )
+        .await
+        .unwrap();
+        let http = HttpClient::new(HttpConfig {
+            max_retries: 0,
+            requests_per_second: 0.0,
+            ..Default::default()
+        })
+        .unwrap()
+        .with_base_url(server.url().clone());
+        let detection = SourceDetector::new()
+            .with_http_client(http)
+            .detect("https://careers.acme.com/")
+            .await
+            .unwrap();
+        assert_eq!(detection.company.source, SourceKind::Greenhouse);
+        assert_eq!(detection.company.url, "https://boards.greenhouse.io/acme");
+        assert_eq!(detection.evidence, "Greenhouse API lists jobs for `acme`");
+    }
+}
@@ -0,0 +1,90 @@
+use crate::adapters::ashby_client::AshbyClient;
+use crate::adapters::feed_source::FeedJobSource;
+use crate::adapters::file_source::FileJobSource;
+use crate::adapters::greenhouse_client::GreenhouseClient;
+use crate::adapters::hacker_news_client::HackerNewsClient;
+use crate::adapters::http::{HttpClient, HttpConfig};
+use crate::adapters::json_ld_source::JsonLdJobSource;
+use crate::adapters::lever_client::LeverClient;
+use crate::adapters::oracle_hcm_client::OracleHcmClient;
+use crate::adapters::smartrecruiters_client::SmartRecruitersClient;
+use crate::adapters::successfactors_client::SuccessFactorsClient;
+use crate::adapters::workable_client::WorkableClient;
+use crate::adapters::workday_client::WorkdayClient;
+use crate::core::model::{Company, Job, SourceKind};
+use crate::domain::job_source::JobSource;
+use crate::domain::llm::LLMProvider;
+use crate::error::ScannerResult;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// A `JobSource` that forwards each company to the client for its `source`.
+pub struct SourceRouter {
+    workday: WorkdayClient,
+    greenhouse: GreenhouseClient,
+    lever: LeverClient,
+    ashby: AshbyClient,
+    smartrecruiters: SmartRecruitersClient,
+    workable: WorkableClient,
+    oracle_hcm: OracleHcmClient,
+    successfactors: SuccessFactorsClient,
+    json_ld: JsonLdJobSource,
+    feed: FeedJobSource,
+    hacker_news: HackerNewsClient,
+    file: FileJobSource,
+}
+
+impl SourceRouter {
+    pub fn new(http: &HttpConfig) -> ScannerResult<Self> {
+        Ok(SourceRouter {
+            workday: WorkdayClient::new().with_http_client(HttpClient::new(http.clone())?),
+            greenhouse: GreenhouseClient::new().with_http_client(HttpClient::new(http.clone())?),
+            lever: LeverClient::new().with_http_client(HttpClient::new(http.clone())?),
+            ashby: AshbyClient::new().with_http_client(HttpClient::new(http.clone())?),
+            smartrecruiters: SmartRecruitersClient::new()
+                .with_http_client(HttpClient::new(http.clone())?),
+            workable: WorkableClient::new().with_http_client(HttpClient::new(http.clone())?),
+            oracle_hcm: OracleHcmClient::new().with_http_client(HttpClient::new(http.clone())?),
+            successfactors: SuccessFactorsClient::new()
+                .with_http_client(HttpClient::new(http.clone())?),
+            json_ld: JsonLdJobSource::new().with_http_client(HttpClient::new(http.clone())?),
+            feed: FeedJobSource::new().with_http_client(HttpClient::new(http.clone())?),
+            hacker_news: HackerNewsClient::new().with_http_client(HttpClient::new(http.clone())?),
+            file: FileJobSource::new(),
+        })
+    }
+
+    /// Lets sources that read free text (Hacker News) have the LLM extract job details.
+    pub fn with_llm(mut self, llm: Arc<dyn LLMProvider + Send + Sync>) -> Self {
+        self.hacker_news = self.hacker_news.with_llm(llm);
+        self
+    }
+
+    fn source_for(&self, company: &Company) -> &(dyn JobSource + Send + Sync) {
+        match company.source {
+            SourceKind::Workday => &self.workday,
+            SourceKind::Greenhouse => &self.greenhouse,
+            SourceKind::Lever => &self.lever,
+            SourceKind::Ashby => &self.ashby,
+            SourceKind::SmartRecruiters => &self.smartrecruiters,
+            SourceKind::Workable => &self.workable,
+            SourceKind::OracleHcm => &self.oracle_hcm,
+            SourceKind::SuccessFactors => &self.successfactors,
+            SourceKind::JsonLd => &self.json_ld,
+            SourceKind::Feed => &self.feed,
+            SourceKind::HackerNews => &self.hacker_news,
+            SourceKind::File => &self.file,
+        }
+    }
+}
+
+#[async_trait]
+impl JobSource for SourceRouter {
+    async fn get_job_list(&self, company: &Company) -> ScannerResult<Vec<Job>> {
+        self.source_for(company).get_job_list(company).await
+    }
+
+    async fn get_job_details(&self, job: &Job, company: &Company) -> ScannerResult<Job> {
+        self.source_for(company).get_job_details(job, company).await
+    }
+}
@@ -1,4 +1,4 @@
-use crate::core::model::Job;
+use crate::core::model::{Compensation, Job, ModelFilterStats};
 use crate::domain::storage::Storage;
 use crate::error::ScannerResult;
 use async_trait::async_trait;
@@ -22,10 +22,70 @@ impl SqliteStorage {
             , created_at DATETIME DEFAULT CURRENT_TIMESTAMP)",
             [],
         )?;
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS llm_filter_stats (model TEXT PRIMARY KEY, returned_ids INTEGER NOT NULL DEFAULT 0,
+            rejected_ids INTEGER NOT NULL DEFAULT 0, updated_at DATETIME DEFAULT CURRENT_TIMESTAMP)",
+            [],
+        )?;
+        for (column, decl) in [
+            ("external_path", "TEXT"),
+            ("posted_at", "DATE"),
+            ("valid_through", "DATE"),
+            ("requisition_id", "TEXT"),
+            ("employment_type", "TEXT"),
+            ("remote_type", "TEXT"),
+            ("country", "TEXT"),
+            ("hiring_organization", "TEXT"),
+            ("department", "TEXT"),
+            ("salary_min", "REAL"),
+            ("salary_max", "REAL"),
+            ("salary_currency", "TEXT"),
+            ("compensation", "TEXT"),
+        ] {
+            add_column_if_missing(&conn, "matched_jobs", column, decl)?;
+        }
         Ok(Self {
             conn: Arc::new(Mutex::new(conn)),
         })
     }
+
+    /// Title filter totals per model, least reliable first.
+    pub async fn filter_stats(&self) -> ScannerResult<Vec<ModelFilterStats>> {
+        let conn = self.conn.lock().await;
+        let mut stmt = conn.prepare(
+            "SELECT model, returned_ids, rejected_ids FROM llm_filter_stats
+            ORDER BY CAST(rejected_ids AS REAL) / MAX(returned_ids, 1) DESC, model",
+        )?;
+        let stats = stmt
+            .query_map([], |row| {
+                Ok(ModelFilterStats {
+                    model: row.get(0)?,
+                    returned_ids: row.get(1)?,
+                    rejected_ids: row.get(2)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(stats)
+    }
+}
+
+/// Databases created before a column existed are upgraded in place.
+fn add_column_if_missing(
+    conn: &Connection,
+    table: &str,
+    column: &str,
+    decl: &str,
+) -> ScannerResult<()> {
+    let mut stmt = conn.prepare(&format!("PRAGMA table_info({table})"))?;
+    let exists = stmt
+        .query_map([], |row| row.get::<_, String>(1))?
+        .collect::<Result<Vec<_>, _>>()?
+        .iter()
+        .any(|name| name == column);
+    if !exists {
+        conn.execute(&format!("ALTER TABLE {table} ADD COLUMN {column} {decl}"), [])?;
+    }
+    Ok(())
 }
 
 #[async_trait]
@@ -49,8 +109,45 @@ impl Storage for SqliteStorage {
     async fn save_job(&self, job: &Job, score: u8, reasoning: String) -> ScannerResult<()> {
         let conn = self.conn.lock().await;
         conn.execute(
-            "INSERT OR REPLACE INTO matched_jobs (id, title, company, location, score, reasoning) VALUES (?, ?, ?, ?, ?, ?)",
-            params![job.id, job.title, job.company_name, job.location, score, reasoning],
+            "INSERT OR REPLACE INTO matched_jobs (id, title, company, location, score, reasoning, external_path, posted_at, valid_through, requisition_id, employment_type, remote_type, country, hiring_organization, department, salary_min, salary_max, salary_currency, compensation)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            params![
+                job.id,
+                job.title,
+                job.company_name,
+                job.location,
+                score,
+                reasoning,
+                job.external_path,
+                job.posted_at.map(|d| d.to_string()),
+                job.valid_through.map(|d| d.to_string()),
+                job.requisition_id,
+                job.employment_type.map(|t| format!("{t:?}")),
+                job.remote_type.map(|t| format!("{t:?}")),
+                job.country,
+                job.hiring_organization,
+                job.department,
+                job.compensation.as_ref().and_then(|c| c.min),
+                job.compensation.as_ref().and_then(|c| c.max),
+                job.compensation.as_ref().and_then(|c| c.currency.clone()),
+                job.compensation.as_ref().map(Compensation::describe),
+            ],
+        )?;
+        Ok(())
+    }
+
+    async fn record_filter_stats(
+        &self,
+        model: &str,
+        returned_ids: usize,
+        rejected_ids: usize,
+    ) -> ScannerResult<()> {
+        let conn = self.conn.lock().await;
+        conn.execute(
+            "INSERT INTO llm_filter_stats (model, returned_ids, rejected_ids) VALUES (?, ?, ?)
+            ON CONFLICT(model) DO UPDATE SET returned_ids = returned_ids + excluded.returned_ids,
+            rejected_ids = rejected_ids + excluded.rejected_ids, updated_at = CURRENT_TIMESTAMP",
+            params![model, returned_ids as u64, rejected_ids as u64],
         )?;
         Ok(())
     }
@@ -0,0 +1,609 @@
+use crate::adapters::http::HttpClient;
+use crate::error::{ScannerError, ScannerResult};
+use crate::{
+    core::model::{Company, EmploymentType, Job},
+    domain::job_source::JobSource,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use scraper::{ElementRef, Html, Selector};
+use std::collections::HashSet;
+use tracing::warn;
+use url::Url;
+
+/// The Indeed style `<source><job>` feed Career Site Builder sites publish next to their
+/// `sitemap.xml`. The spelling is SAP's.
+const JOB_FEED_PATH: &str = "/sitemal.xml";
+
+/// A posting as found on a search results page or in an XML feed.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SuccessFactorsPosting {
+    pub id: String,
+    pub title: String,
+    /// Absolute URL of the job page.
+    pub url: String,
+    pub location: String,
+    pub posted_on: Option<NaiveDate>,
+    pub requisition_id: Option<String>,
+    pub department: Option<String>,
+    pub employment_type: Option<String>,
+    pub country: Option<String>,
+    pub description: Option<String>,
+}
+
+/// One page of `/search/` results.
+#[derive(Debug, Default)]
+pub struct SuccessFactorsSearchPage {
+    pub postings: Vec<SuccessFactorsPosting>,
+    /// Parsed from the "Results 1 – 25 of 143" label, absent when the site hides it.
+    pub total: Option<usize>,
+}
+
+/// What a job page adds to a posting.
+#[derive(Debug, Default)]
+pub struct SuccessFactorsJobPage {
+    pub title: Option<String>,
+    pub location: Option<String>,
+    pub posted_on: Option<NaiveDate>,
+    pub description: Option<String>,
+}
+
+impl SuccessFactorsPosting {
+    pub fn into_job(self, company_name: &str) -> Job {
+        Job {
+            id: self.id,
+            title: self.title,
+            location: self.location,
+            description: self.description,
+            external_path: self.url,
+            company_name: company_name.to_string(),
+            posted_at: self.posted_on,
+            requisition_id: self.requisition_id,
+            employment_type: self
+                .employment_type
+                .as_deref()
+                .and_then(EmploymentType::from_label),
+            country: self.country,
+            department: self.department,
+            ..Default::default()
+        }
+    }
+}
+
+fn selector(css: &str) -> Selector {
+    Selector::parse(css).expect("static selector")
+}
+
+fn text_of(element: ElementRef) -> String {
+    element
+        .text()
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn first_text(element: ElementRef, css: &str) -> Option<String> {
+    element
+        .select(&selector(css))
+        .map(text_of)
+        .find(|t| !t.is_empty())
+}
+
+/// Career site job URLs end in `/job/{slug}/{id}/`; the numeric id is stable across locales.
+fn posting_id(job_url: &str) -> Option<String> {
+    let parsed = Url::parse(job_url).ok()?;
+    parsed
+        .path_segments()?
+        .rfind(|s| !s.is_empty())
+        .filter(|s| s.chars().all(|c| c.is_ascii_digit()))
+        .map(str::to_string)
+}
+
+/// Accepts the formats career sites print ("May 1, 2024", "1 May 2024"), the
+/// `datePosted` microdata ("Wed May 01 02:00:00 UTC 2024") and the feeds' RFC 2822 dates.
+pub fn parse_successfactors_date(raw: &str) -> Option<NaiveDate> {
+    let raw = raw.trim();
+    for format in ["%b %d, %Y", "%d %b %Y", "%Y-%m-%d", "%m/%d/%Y", "%d.%m.%Y"] {
+        if let Ok(date) = NaiveDate::parse_from_str(raw, format) {
+            return Some(date);
+        }
+    }
+    if let Ok(date) = NaiveDateTime::parse_from_str(raw, "%a %b %d %H:%M:%S UTC %Y") {
+        return Some(date.date());
+    }
+    DateTime::parse_from_rfc2822(raw)
+        .or_else(|_| DateTime::parse_from_rfc3339(raw))
+        .ok()
+        .map(|d| d.date_naive())
+}
+
+/// Parses the `tr.data-row` table of a `/search/` results page. `base` resolves the
+/// relative job links.
+pub fn parse_search_page(html: &str, base: &Url) -> SuccessFactorsSearchPage {
+    let document = Html::parse_document(html);
+    let postings = document
+        .select(&selector("tr.data-row"))
+        .filter_map(|row| {
+            let link = row.select(&selector("a.jobTitle-link")).next()?;
+            let url = base.join(link.value().attr("href")?).ok()?.to_string();
+            Some(SuccessFactorsPosting {
+                id: posting_id(&url)?,
+                title: text_of(link),
+                location: first_text(row, ".jobLocation").unwrap_or_default(),
+                posted_on: first_text(row, ".jobDate")
+                    .as_deref()
+                    .and_then(parse_successfactors_date),
+                department: first_text(row, ".jobDepartment"),
+                url,
+                ..Default::default()
+            })
+        })
+        .collect();
+    let total = document
+        .select(&selector(".paginationLabel"))
+        .next()
+        .map(text_of)
+        .and_then(|label| {
+            label
+                .replace(',', "")
+                .split(|c: char| !c.is_ascii_digit())
+                .rfind(|n| !n.is_empty())
+                .and_then(|n| n.parse().ok())
+        });
+    SuccessFactorsSearchPage { postings, total }
+}
+
+/// Parses a job page, preferring the career site property markers over the schema.org
+/// microdata both templates carry.
+pub fn parse_job_page(html: &str) -> SuccessFactorsJobPage {
+    let document = Html::parse_document(html);
+    let root = document.root_element();
+    let posted_on = root
+        .select(&selector("[itemprop=\"datePosted\"]"))
+        .next()
+        .and_then(|e| {
+            e.value()
+                .attr("content")
+                .map(str::to_string)
+                .or(Some(text_of(e)))
+        })
+        .or_else(|| first_text(root, "[data-careersite-propertyid=\"date\"]"))
+        .as_deref()
+        .and_then(parse_successfactors_date);
+    SuccessFactorsJobPage {
+        title: first_text(root, "[data-careersite-propertyid=\"title\"]")
+            .or_else(|| first_text(root, "[itemprop=\"title\"]")),
+        location: first_text(root, ".jobGeoLocation")
+            .or_else(|| first_text(root, "[data-careersite-propertyid=\"location\"]")),
+        posted_on,
+        description: root
+            .select(&selector("[itemprop=\"description\"], .jobdescription"))
+            .next()
+            .map(|e| e.inner_html().trim().to_string())
+            .filter(|d| !d.is_empty()),
+    }
+}
+
+fn child_text(node: roxmltree::Node, name: &str) -> Option<String> {
+    node.children()
+        .find(|c| c.tag_name().name() == name)
+        .and_then(|c| c.text())
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty())
+}
+
+/// Turns the slug of `/job/Walldorf-Developer-BW-69190/1052345601/` into readable text. A
+/// sitemap carries nothing else, so it stands in for the title until the job page is read.
+fn slug_title(job_url: &str) -> String {
+    Url::parse(job_url)
+        .ok()
+        .and_then(|u| {
+            u.path_segments()?
+                .filter(|s| !s.is_empty())
+                .rev()
+                .nth(1)
+                .map(|slug| slug.replace('-', " "))
+        })
+        .unwrap_or_default()
+}
+
+/// Parses either XML feed a career site publishes: the Indeed style `<source><job>` feed,
+/// which carries titles and descriptions, or the `<urlset>` job sitemap.
+///
+/// Sitemap entries only have a URL, so their titles are read from the URL slug and they
+/// get no posting date: `<lastmod>` is when the page last changed, not when the job was
+/// posted. Both are filled in from the job page by `get_job_details`.
+pub fn parse_job_feed(xml: &str) -> ScannerResult<Vec<SuccessFactorsPosting>> {
+    let document = roxmltree::Document::parse(xml)?;
+    let root = document.root_element();
+    let postings = match root.tag_name().name() {
+        "urlset" => root
+            .children()
+            .filter(|n| n.tag_name().name() == "url")
+            .filter_map(|entry| {
+                let url = child_text(entry, "loc")?;
+                Some(SuccessFactorsPosting {
+                    id: posting_id(&url)?,
+                    title: slug_title(&url),
+                    url,
+                    ..Default::default()
+                })
+            })
+            .collect(),
+        _ => root
+            .descendants()
+            .filter(|n| n.tag_name().name() == "job")
+            .filter_map(|job| {
+                let url = child_text(job, "url")?;
+                let requisition_id = child_text(job, "referencenumber");
+                let location = ["city", "state", "country"]
+                    .into_iter()
+                    .filter_map(|field| child_text(job, field))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                Some(SuccessFactorsPosting {
+                    id: posting_id(&url).or(requisition_id.clone())?,
+                    title: child_text(job, "title")?,
+                    location,
+                    posted_on: child_text(job, "date")
+                        .as_deref()
+                        .and_then(parse_successfactors_date),
+                    requisition_id,
+                    department: child_text(job, "category"),
+                    employment_type: child_text(job, "jobtype"),
+                    country: child_text(job, "country"),
+                    description: child_text(job, "description"),
+                    url,
+                })
+            })
+            .collect(),
+    };
+    Ok(postings)
+}
+
+/// Validates a career site URL: either a page on the site (`/search/?q=...` and friends)
+/// or one of its `.xml` job feeds.
+fn successfactors_site(company_url: &str) -> ScannerResult<Url> {
+    let invalid =
+        |reason: &str| ScannerError::SourceUrlError(company_url.to_string(), reason.to_string());
+
+    let parsed_url = Url::parse(company_url)?;
+    if parsed_url.host_str().is_none() {
+        return Err(invalid("missing host"));
+    }
+    if !matches!(parsed_url.scheme(), "http" | "https") {
+        return Err(invalid("scheme must be http or https"));
+    }
+    Ok(parsed_url)
+}
+
+fn is_feed(site: &Url) -> bool {
+    site.path().ends_with(".xml")
+}
+
+fn is_sitemap(xml: &str) -> bool {
+    roxmltree::Document::parse(xml).is_ok_and(|d| d.root_element().has_tag_name("urlset"))
+}
+
+/// Builds a `/search/` URL keeping the filters already present on the configured one and
+/// adding `Company::facets` as raw query parameters (`locationsearch`,
+/// `optionsFacetsDD_country`, ...).
+fn search_url(site: &Url, query: Option<&str>, facets: &[(&str, &str)], startrow: usize) -> Url {
+    let mut url = site.clone();
+    url.set_path("/search/");
+    let site_query = site
+        .query_pairs()
+        .find(|(k, _)| k == "q")
+        .map(|(_, v)| v.into_owned());
+    let kept: Vec<(String, String)> = site
+        .query_pairs()
+        .filter(|(k, _)| k != "q" && k != "startrow")
+        .map(|(k, v)| (k.into_owned(), v.into_owned()))
+        .collect();
+    {
+        let mut pairs = url.query_pairs_mut();
+        pairs.clear();
+        pairs.append_pair("q", query.or(site_query.as_deref()).unwrap_or_default());
+        pairs.extend_pairs(kept);
+        pairs.extend_pairs(facets);
+        pairs.append_pair("startrow", &startrow.to_string());
+    }
+    url
+}
+
+/// Reads SAP SuccessFactors career sites, e.g. `https://careers.acme.com/search/?q=`, by
+/// scraping their search pages or from one of their XML job feeds.
+pub struct SuccessFactorsClient {
+    http: HttpClient,
+    max_pages: u32,
+}
+
+impl Default for SuccessFactorsClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SuccessFactorsClient {
+    pub fn new() -> Self {
+        SuccessFactorsClient {
+            http: HttpClient::default(),
+            max_pages: 40,
+        }
+    }
+
+    pub fn with_http_client(mut self, http: HttpClient) -> Self {
+        self.http = http;
+        self
+    }
+
+    async fn fetch_text(&self, url: &str) -> ScannerResult<String> {
+        let resp = self.http.send(self.http.get(url)).await?;
+        Ok(resp.text().await?)
+    }
+
+    /// Reads the configured feed. A job sitemap only yields slug titles, so the
+    /// `<source><job>` feed at `JOB_FEED_PATH` is used instead whenever the site has one.
+    async fn read_feed(&self, feed_url: &Url) -> ScannerResult<Vec<SuccessFactorsPosting>> {
+        let xml = self.fetch_text(feed_url.as_str()).await?;
+        if is_sitemap(&xml) {
+            let job_feed = feed_url.join(JOB_FEED_PATH)?;
+            match self.fetch_text(job_feed.as_str()).await {
+                Ok(feed) if !is_sitemap(&feed) => match parse_job_feed(&feed) {
+                    Ok(postings) if !postings.is_empty() => return Ok(postings),
+                    _ => warn!("{job_feed} is not a job feed, using the sitemap"),
+                },
+                Ok(_) => {}
+                Err(e) => warn!("no job feed at {job_feed} ({e}), using the sitemap"),
+            }
+        }
+        parse_job_feed(&xml)
+    }
+
+    async fn search(
+        &self,
+        site: &Url,
+        query: Option<&str>,
+        facets: &[(&str, &str)],
+    ) -> ScannerResult<Vec<SuccessFactorsPosting>> {
+        let mut postings = Vec::new();
+        for _ in 0..self.max_pages {
+            let url = search_url(site, query, facets, postings.len());
+            let page = parse_search_page(&self.fetch_text(url.as_str()).await?, site);
+            let page_len = page.postings.len();
+            postings.extend(page.postings);
+            if page_len == 0 || page.total.is_none_or(|total| postings.len() >= total) {
+                break;
+            }
+        }
+        Ok(postings)
+    }
+}
+
+#[async_trait]
+impl JobSource for SuccessFactorsClient {
+    async fn get_job_list(&self, company: &Company) -> ScannerResult<Vec<Job>> {
+        let site = successfactors_site(&company.url)?;
+        let postings = if is_feed(&site) {
+            self.read_feed(&site).await?
+        } else {
+            let facets: Vec<(&str, &str)> = company
+                .facets
+                .iter()
+                .flat_map(|(k, values)| values.iter().map(move |v| (k.as_str(), v.as_str())))
+                .collect();
+            let queries: Vec<Option<&str>> = if company.search_queries.is_empty() {
+                vec![None]
+            } else {
+                company
+                    .search_queries
+                    .iter()
+                    .map(|q| Some(q.as_str()))
+                    .collect()
+            };
+            let mut postings = Vec::new();
+            for query in queries {
+                postings.extend(self.search(&site, query, &facets).await?);
+            }
+            postings
+        };
+
+        let mut seen = HashSet::new();
+        Ok(postings
+            .into_iter()
+            .filter(|p| seen.insert(p.id.clone()))
+            .map(|p| p.into_job(&company.name))
+            .collect())
+    }
+
+    /// Feed postings may already carry a description; otherwise the job page is scraped,
+    /// which also replaces sitemap slug titles with the real ones.
+    async fn get_job_details(&self, job: &Job, _company: &Company) -> ScannerResult<Job> {
+        if job.description.is_some() {
+            return Ok(job.clone());
+        }
+        let page = parse_job_page(&self.fetch_text(&job.external_path).await?);
+        let mut job = job.clone();
+        if let Some(title) = page.title {
+            job.title = title;
+        }
+        if job.location.is_empty() {
+            job.location = page.location.unwrap_or_default();
+        }
+        job.posted_at = job.posted_at.or(page.posted_on);
+        job.description = page.description;
+        Ok(job)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::http::HttpConfig;
+    use crate::adapters::replay::ReplayServer;
+
+    #[test]
+    fn test_search_url_keeps_site_filters() {
+        let site =
+            successfactors_site("https://careers.acme.com/search/?q=&locationsearch=Pune").unwrap();
+        assert_eq!(
+            search_url(&site, None, &[], 25).as_str(),
+            "https://careers.acme.com/search/?q=&locationsearch=Pune&startrow=25"
+        );
+        assert_eq!(
+            search_url(
+                &site,
+                Some("rust dev"),
+                &[("optionsFacetsDD_country", "IN")],
+                0
+            )
+            .as_str(),
+            "https://careers.acme.com/search/?q=rust+dev&locationsearch=Pune&optionsFacetsDD_country=IN&startrow=0"
+        );
+        assert!(is_feed(
+            &successfactors_site("https://careers.acme.com/sitemap.xml").unwrap()
+        ));
+        assert!(successfactors_site("careers.acme.com/search/").is_err());
+    }
+
+    #[test]
+    fn test_parse_search_and_job_pages() {
+        let html = r#"
+            <table id="searchresults"><tbody>
+              <tr class="data-row">
+                <td class="colTitle">
+                  <span class="jobTitle hidden-phone">
+                    <a href="/job/Pune-Software-Engineer-MH/1052345601/" class="jobTitle-link">Software  Engineer</a>
+                  </span>
+                  <div class="jobdetail-phone visible-phone">
+                    <span class="jobTitle visible-phone">
+                      <a class="jobTitle-link" href="/job/Pune-Software-Engineer-MH/1052345601/">Software Engineer</a>
+                    </span>
+                  </div>
+                </td>
+                <td class="colLocation hidden-phone"><span class="jobLocation">Pune, MH, IN</span></td>
+                <td class="colDepartment hidden-phone"><span class="jobDepartment">Engineering</span></td>
+                <td class="colDate hidden-phone"><span class="jobDate">May 1, 2024</span></td>
+              </tr>
+            </tbody></table>
+            <span class="paginationLabel">Results <b>1 – 1</b> of <b>1,143</b></span>
+        "#;
+        let base = Url::parse("https://careers.acme.com/search/?q=").unwrap();
+        let page = parse_search_page(html, &base);
+        assert_eq!(page.total, Some(1143));
+        assert_eq!(
+            page.postings,
+            vec![SuccessFactorsPosting {
+                id: "1052345601".to_string(),
+                title: "Software Engineer".to_string(),
+                url: "https://careers.acme.com/job/Pune-Software-Engineer-MH/1052345601/"
+                    .to_string(),
+                location: "Pune, MH, IN".to_string(),
+                posted_on: NaiveDate::from_ymd_opt(2024, 5, 1),
+                department: Some("Engineering".to_string()),
+                ..Default::default()
+            }]
+        );
+
+        let html = r#"
+            <div itemscope itemtype="http://schema.org/JobPosting">
+              <h1><span itemprop="title" data-careersite-propertyid="title">Software Engineer</span></h1>
+              <span class="jobGeoLocation">Pune, MH, IN</span>
+              <meta itemprop="datePosted" content="Wed May 01 02:00:00 UTC 2024">
+              <span class="jobdescription" itemprop="description"><p>Build things.</p></span>
+            </div>
+        "#;
+        let page = parse_job_page(html);
+        assert_eq!(page.title.as_deref(), Some("Software Engineer"));
+        assert_eq!(page.location.as_deref(), Some("Pune, MH, IN"));
+        assert_eq!(page.posted_on, NaiveDate::from_ymd_opt(2024, 5, 1));
+        assert_eq!(page.description.as_deref(), Some("<p>Build things.</p>"));
+    }
+
+    #[test]
+    fn test_parse_job_feeds() {
+        let feed = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <source>
+              <publisher>Acme</publisher>
+              <job>
+                <title><![CDATA[Data Analyst]]></title>
+                <date><![CDATA[Wed, 01 May 2024 00:00:00 GMT]]></date>
+                <referencenumber><![CDATA[REQ-42]]></referencenumber>
+                <url><![CDATA[https://careers.acme.com/job/Berlin-Data-Analyst/1052345602/]]></url>
+                <city><![CDATA[Berlin]]></city>
+                <country><![CDATA[DE]]></country>
+                <jobtype><![CDATA[Full time]]></jobtype>
+                <description><![CDATA[<p>Dashboards.</p>]]></description>
+              </job>
+            </source>"#;
+        let job = parse_job_feed(feed).unwrap().remove(0).into_job("Acme");
+        assert_eq!(job.id, "1052345602");
+        assert_eq!(job.title, "Data Analyst");
+        assert_eq!(job.location, "Berlin, DE");
+        assert_eq!(job.requisition_id.as_deref(), Some("REQ-42"));
+        assert_eq!(job.employment_type, Some(EmploymentType::FullTime));
+        assert_eq!(job.posted_at, NaiveDate::from_ymd_opt(2024, 5, 1));
+        assert_eq!(job.description.as_deref(), Some("<p>Dashboards.</p>"));
+
+        let sitemap = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+              <url><loc>https://careers.acme.com/</loc></url>
+              <url>
+                <loc>https://careers.acme.com/job/Walldorf-Developer-BW-69190/1052345603/</loc>
+                <lastmod>2024-05-01</lastmod>
+              </url>
+            </urlset>"#;
+        let postings = parse_job_feed(sitemap).unwrap();
+        assert_eq!(postings.len(), 1);
+        assert_eq!(postings[0].id, "1052345603");
+        assert_eq!(postings[0].title, "Walldorf Developer BW 69190");
+        assert_eq!(postings[0].posted_on, None);
+        assert!(parse_job_feed("<source>").is_err());
+    }
+
+    async fn sitemap_jobs(fixtures: &str) -> Vec<Job> {
+        let server = ReplayServer::start(fixtures, "https://careers.acme.com")
+            .await
+            .unwrap();
+        let http = HttpClient::new(HttpConfig {
+            max_retries: 0,
+            requests_per_second: 0.0,
+            ..Default::default()
+        })
+        .unwrap()
+        .with_base_url(server.url().clone());
+        let company = Company {
+            name: "Acme".to_string(),
+            url: "https://careers.acme.com/sitemap.xml".to_string(),
+            ..Default::default()
+        };
+        SuccessFactorsClient::new()
+            .with_http_client(http)
+            .get_job_list(&company)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_sitemap_prefers_the_job_feed() {
+        let jobs = sitemap_jobs(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/replay/successfactors_feeds"
+        ))
+        .await;
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].title, "Developer (f/m/d)");
+        assert_eq!(jobs[0].posted_at, NaiveDate::from_ymd_opt(2024, 5, 1));
+
+        // without a job feed the sitemap is used, with slug titles and no posting date
+        let jobs = sitemap_jobs(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/replay/successfactors_sitemap_only"
+        ))
+        .await;
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].title, "Walldorf Developer BW 69190");
+        assert_eq!(jobs[0].posted_at, None);
+    }
+}
@@ -0,0 +1,275 @@
+use crate::adapters::http::HttpClient;
+use crate::error::{ScannerError, ScannerResult};
+use crate::{
+    core::model::{Company, EmploymentType, Job, RemoteType},
+    domain::job_source::JobSource,
+};
+use async_trait::async_trait;
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+const API_BASE: &str = "https://apply.workable.com/api";
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[allow(non_snake_case)]
+pub struct WorkableLocation {
+    pub city: Option<String>,
+    pub region: Option<String>,
+    pub country: Option<String>,
+    pub countryCode: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct WorkableJob {
+    pub shortcode: String,
+    pub title: String,
+    #[serde(default)]
+    pub remote: bool,
+    #[serde(default)]
+    pub location: WorkableLocation,
+    pub published: Option<String>,
+    /// `full`, `part`, `contract`, `temporary`, ...
+    #[serde(rename = "type")]
+    pub employment_type: Option<String>,
+    #[serde(default)]
+    pub department: Vec<String>,
+    /// `on_site`, `hybrid` or `remote`.
+    pub workplace: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[allow(non_snake_case)]
+pub struct WorkableJobPage {
+    pub total: usize,
+    pub results: Vec<WorkableJob>,
+    /// Opaque token for the following page, absent on the last one.
+    pub nextPage: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct WorkableJobDetails {
+    pub description: Option<String>,
+    pub requirements: Option<String>,
+    pub benefits: Option<String>,
+}
+
+impl WorkableJobDetails {
+    fn full_description(self) -> String {
+        [
+            (None, self.description),
+            (Some("Requirements"), self.requirements),
+            (Some("Benefits"), self.benefits),
+        ]
+        .into_iter()
+        .filter_map(|(title, html)| {
+            let html = html?;
+            Some(match title {
+                Some(title) => format!("<h3>{title}</h3>{html}"),
+                None => html,
+            })
+        })
+        .collect()
+    }
+}
+
+impl WorkableJob {
+    pub fn into_job(self, company_name: &str, slug: &str) -> Job {
+        let location = [
+            &self.location.city,
+            &self.location.region,
+            &self.location.country,
+        ]
+        .into_iter()
+        .flatten()
+        .cloned()
+        .collect::<Vec<_>>()
+        .join(", ");
+        let remote_type = self
+            .workplace
+            .as_deref()
+            .and_then(RemoteType::from_label)
+            .or(self.remote.then_some(RemoteType::Remote));
+        Job {
+            external_path: format!("https://apply.workable.com/{slug}/j/{}/", self.shortcode),
+            id: self.shortcode,
+            title: self.title,
+            location,
+            company_name: company_name.to_string(),
+            posted_at: self
+                .published
+                .and_then(|d| DateTime::parse_from_rfc3339(&d).ok())
+                .map(|d| d.date_naive()),
+            employment_type: self
+                .employment_type
+                .as_deref()
+                .and_then(EmploymentType::from_label),
+            remote_type,
+            country: self.location.country,
+            department: (!self.department.is_empty()).then(|| self.department.join(", ")),
+            ..Default::default()
+        }
+    }
+}
+
+/// Extracts the account slug from `apply.workable.com/{slug}`, `{slug}.workable.com`
+/// or an `apply.workable.com/api/v3/accounts/{slug}/jobs` URL.
+pub fn workable_account_slug(company_url: &str) -> ScannerResult<String> {
+    let invalid =
+        |reason: &str| ScannerError::SourceUrlError(company_url.to_string(), reason.to_string());
+
+    let parsed_url = Url::parse(company_url)?;
+    let domain = parsed_url.domain().ok_or_else(|| invalid("missing host"))?;
+    let path_segments: Vec<&str> = parsed_url
+        .path_segments()
+        .map(|segments| segments.filter(|s| !s.is_empty()).collect())
+        .unwrap_or_default();
+    match (domain, path_segments.as_slice()) {
+        ("apply.workable.com", ["api", _, "accounts", slug, ..]) => Ok(slug.to_string()),
+        ("apply.workable.com", ["api", ..]) => Err(invalid(
+            "API path must look like /api/v3/accounts/{slug}/jobs",
+        )),
+        ("apply.workable.com", [slug, ..]) => Ok(slug.to_string()),
+        ("apply.workable.com", []) => Err(invalid("missing account slug in path")),
+        (domain, _) => match domain.strip_suffix(".workable.com") {
+            Some(slug) if !slug.contains('.') && slug != "www" => Ok(slug.to_string()),
+            _ => Err(invalid("host is not apply.workable.com or {slug}.workable.com")),
+        },
+    }
+}
+
+/// Reads a company's public Workable careers page.
+pub struct WorkableClient {
+    http: HttpClient,
+}
+
+impl Default for WorkableClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WorkableClient {
+    pub fn new() -> Self {
+        WorkableClient {
+            http: HttpClient::default(),
+        }
+    }
+
+    pub fn with_http_client(mut self, http: HttpClient) -> Self {
+        self.http = http;
+        self
+    }
+}
+
+#[async_trait]
+impl JobSource for WorkableClient {
+    /// Follows the `nextPage` token until Workable stops returning one.
+    async fn get_job_list(&self, company: &Company) -> ScannerResult<Vec<Job>> {
+        let slug = workable_account_slug(&company.url)?;
+        let url = format!("{API_BASE}/v3/accounts/{slug}/jobs");
+        let mut jobs = Vec::new();
+        let mut token: Option<String> = None;
+        loop {
+            let mut body = serde_json::json!({
+                "query": "",
+                "location": [],
+                "department": [],
+                "worktype": [],
+                "remote": []
+            });
+            if let Some(token) = &token {
+                body["token"] = serde_json::Value::String(token.clone());
+            }
+            let resp = self.http.send(self.http.post(&url).json(&body)).await?;
+            let page = resp.json::<WorkableJobPage>().await?;
+            let page_len = page.results.len();
+            jobs.extend(
+                page.results
+                    .into_iter()
+                    .map(|job| job.into_job(&company.name, &slug)),
+            );
+            match page.nextPage {
+                Some(next) if page_len > 0 && jobs.len() < page.total => token = Some(next),
+                _ => break,
+            }
+        }
+        Ok(jobs)
+    }
+
+    async fn get_job_details(&self, job: &Job, company: &Company) -> ScannerResult<Job> {
+        let slug = workable_account_slug(&company.url)?;
+        let url = format!("{API_BASE}/v2/accounts/{slug}/jobs/{}", job.id);
+        let resp = self.http.send(self.http.get(&url)).await?;
+        let details = resp.json::<WorkableJobDetails>().await?;
+        Ok(Job {
+            description: Some(details.full_description()),
+            ..job.clone()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_parse_workable_account_slug() {
+        for url in [
+            "https://apply.workable.com/acme/",
+            "https://apply.workable.com/acme/j/AB12CD34EF/",
+            "https://apply.workable.com/api/v3/accounts/acme/jobs",
+            "https://acme.workable.com",
+        ] {
+            assert_eq!(workable_account_slug(url).unwrap(), "acme", "{url}");
+        }
+        assert!(workable_account_slug("https://apply.workable.com/").is_err());
+        assert!(workable_account_slug("https://acme.com/careers").is_err());
+    }
+
+    #[test]
+    fn test_workable_job_and_details() {
+        let page = r#"
+            {
+                "total": 1,
+                "results": [
+                    {
+                        "id": 3456789,
+                        "shortcode": "AB12CD34EF",
+                        "title": "Data Engineer",
+                        "remote": false,
+                        "location": { "country": "Germany", "countryCode": "DE", "city": "Berlin", "region": "Berlin" },
+                        "state": "published",
+                        "published": "2024-05-01T00:00:00.000Z",
+                        "type": "full",
+                        "department": ["Data", "Engineering"],
+                        "workplace": "on_site"
+                    }
+                ]
+            }
+        "#;
+        let page = serde_json::from_str::<WorkableJobPage>(page).unwrap();
+        assert!(page.nextPage.is_none());
+        let job = page.results.into_iter().next().unwrap().into_job("Acme", "acme");
+        assert_eq!(job.id, "AB12CD34EF");
+        assert_eq!(job.location, "Berlin, Berlin, Germany");
+        assert_eq!(job.department.as_deref(), Some("Data, Engineering"));
+        assert_eq!(job.employment_type, Some(EmploymentType::FullTime));
+        assert_eq!(job.remote_type, Some(RemoteType::OnSite));
+        assert_eq!(job.posted_at, NaiveDate::from_ymd_opt(2024, 5, 1));
+        assert_eq!(
+            job.apply_url(""),
+            "https://apply.workable.com/acme/j/AB12CD34EF/"
+        );
+
+        let details = r#"
+            { "description": "<p>Pipelines.</p>", "requirements": "<ul><li>SQL</li></ul>", "benefits": null }
+        "#;
+        let details = serde_json::from_str::<WorkableJobDetails>(details).unwrap();
+        assert_eq!(
+            details.full_description(),
+            "<p>Pipelines.</p><h3>Requirements</h3><ul><li>SQL</li></ul>"
+        );
+    }
+}
@@ -1,11 +1,14 @@
 use async_trait::async_trait;
-use reqwest::Client;
+use chrono::{Days, Local, NaiveDate};
+use std::collections::HashSet;
+use tracing::error;
 use serde::{Deserialize, Serialize};
-use url::{Url, ParseError::EmptyHost};
+use url::Url;
 use serde_json::{Map, Value};
+use crate::adapters::http::HttpClient;
 use crate::error::{ScannerError, ScannerResult};
 use crate::{
-    core::model::{Company, Job},
+    core::model::{Company, EmploymentType, Job, RemoteType},
     domain::job_source::JobSource,
 };
 
@@ -15,6 +18,11 @@ pub struct WorkdayJobInfo {
     pub title: String,
     pub externalPath: String,
     pub locationsText: String,
+    pub postedOn: Option<String>,
+    /// Usually holds the requisition id, e.g. `["R123456"]`.
+    #[serde(default)]
+    pub bulletFields: Vec<String>,
+    pub remoteType: Option<String>,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -22,6 +30,124 @@ pub struct WorkdayJobInfo {
 pub struct WorkdayJobPosting {
     pub total: i32,
     pub jobPostings: Vec<WorkdayJobInfo>,
+    #[serde(default)]
+    pub facets: Vec<WorkdayFacet>,
+}
+
+/// A node of the `facets` tree returned by `/jobs`.
+///
+/// Groups (e.g. `locationMainGroup`) carry a `facetParameter` and nested `values`,
+/// selectable leaves carry an `id` and a human readable `descriptor`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[allow(non_snake_case)]
+pub struct WorkdayFacet {
+    pub facetParameter: Option<String>,
+    pub descriptor: Option<String>,
+    pub id: Option<String>,
+    pub count: Option<u32>,
+    #[serde(default)]
+    pub values: Vec<WorkdayFacet>,
+}
+
+/// A single selectable facet value, flattened out of the `facets` tree.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FacetOption {
+    pub parameter: String,
+    pub id: String,
+    pub descriptor: String,
+    pub count: u32,
+}
+
+pub fn flatten_facets(facets: &[WorkdayFacet]) -> Vec<FacetOption> {
+    fn walk(facet: &WorkdayFacet, parameter: Option<&str>, out: &mut Vec<FacetOption>) {
+        if let (Some(parameter), Some(id), Some(descriptor)) =
+            (parameter, &facet.id, &facet.descriptor)
+        {
+            out.push(FacetOption {
+                parameter: parameter.to_string(),
+                id: id.clone(),
+                descriptor: descriptor.clone(),
+                count: facet.count.unwrap_or(0),
+            });
+        }
+        let parameter = facet.facetParameter.as_deref().or(parameter);
+        for value in &facet.values {
+            walk(value, parameter, out);
+        }
+    }
+
+    let mut out = Vec::new();
+    for facet in facets {
+        walk(facet, None, &mut out);
+    }
+    out
+}
+
+/// Converts Workday's relative freshness labels ("Posted Today", "Posted Yesterday",
+/// "Posted 3 Days Ago", "Posted 30+ Days Ago") into an approximate posting date.
+///
+/// Open ended values such as "30+" resolve to their lower bound, so a posting is never
+/// considered older than it could be.
+pub fn parse_posted_on(posted_on: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let text = posted_on.trim().to_lowercase();
+    let text = text.strip_prefix("posted").unwrap_or(&text).trim();
+    let days = match text {
+        "today" => 0,
+        "yesterday" => 1,
+        _ => {
+            let count = text.strip_suffix("ago")?.trim();
+            let count = count
+                .strip_suffix("days")
+                .or_else(|| count.strip_suffix("day"))?
+                .trim()
+                .trim_end_matches('+');
+            count.parse::<u64>().ok()?
+        }
+    };
+    today.checked_sub_days(Days::new(days))
+}
+
+/// Workday facet ids are 32 character lowercase hex strings.
+fn is_facet_id(value: &str) -> bool {
+    value.len() == 32 && value.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Resolves a facet value given either as an id or as a descriptor ("India", "Pune").
+///
+/// Descriptors match case-insensitively; when nothing matches exactly every option whose
+/// descriptor contains the value is used, so "Pune" also selects "Pune - Hinjewadi".
+fn resolve_facet_value(
+    options: &[FacetOption],
+    parameter: &str,
+    value: &str,
+) -> ScannerResult<Vec<String>> {
+    if is_facet_id(value) {
+        return Ok(vec![value.to_string()]);
+    }
+    let candidates: Vec<&FacetOption> = options
+        .iter()
+        .filter(|o| o.parameter == parameter)
+        .collect();
+    let needle = value.to_lowercase();
+    let exact: Vec<String> = candidates
+        .iter()
+        .filter(|o| o.descriptor.to_lowercase() == needle)
+        .map(|o| o.id.clone())
+        .collect();
+    if !exact.is_empty() {
+        return Ok(exact);
+    }
+    let partial: Vec<String> = candidates
+        .iter()
+        .filter(|o| o.descriptor.to_lowercase().contains(&needle))
+        .map(|o| o.id.clone())
+        .collect();
+    if partial.is_empty() {
+        return Err(ScannerError::FacetError(format!(
+            "no `{parameter}` facet matches \"{value}\""
+        )));
+    }
+    Ok(partial)
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -29,136 +155,352 @@ pub struct WorkdayJobPosting {
 pub struct JobPostingInfo {
     pub title: String,
     pub jobDescription: String,
+    pub location: Option<String>,
+    pub postedOn: Option<String>,
+    /// ISO date (`2024-05-01`) the posting went live.
+    pub startDate: Option<String>,
+    pub timeType: Option<String>,
+    pub jobReqId: Option<String>,
+    pub remoteType: Option<String>,
+    pub country: Option<WorkdayDescriptor>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct WorkdayDescriptor {
+    pub descriptor: String,
 }
 
 #[derive(Debug, Deserialize)]
 #[allow(non_snake_case)]
 pub struct JobPostingDetails {
     pub jobPostingInfo: JobPostingInfo,
+    pub hiringOrganization: Option<WorkdayDescriptor>,
+}
+
+impl JobPostingDetails {
+    /// Fills `job` with the description and metadata only the detail endpoint returns,
+    /// keeping what the listing already provided when the detail omits it.
+    pub fn apply_to(self, job: &Job) -> Job {
+        let info = self.jobPostingInfo;
+        Job {
+            description: Some(info.jobDescription),
+            location: info.location.unwrap_or_else(|| job.location.clone()),
+            posted_at: info
+                .startDate
+                .and_then(|d| NaiveDate::parse_from_str(&d, "%Y-%m-%d").ok())
+                .or(job.posted_at)
+                .or_else(|| {
+                    info.postedOn
+                        .as_deref()
+                        .and_then(|p| parse_posted_on(p, Local::now().date_naive()))
+                }),
+            requisition_id: info.jobReqId.or_else(|| job.requisition_id.clone()),
+            employment_type: info
+                .timeType
+                .as_deref()
+                .and_then(EmploymentType::from_label)
+                .or(job.employment_type),
+            remote_type: info
+                .remoteType
+                .as_deref()
+                .and_then(RemoteType::from_label)
+                .or(job.remote_type),
+            country: info.country.map(|c| c.descriptor).or_else(|| job.country.clone()),
+            hiring_organization: self
+                .hiringOrganization
+                .map(|o| o.descriptor)
+                .or_else(|| job.hiring_organization.clone()),
+            ..job.clone()
+        }
+    }
 }
 
 pub struct WorkdayClient {
-    client: Client,
-    limit: u16,
-    offset: u16,
+    http: HttpClient,
+    page_size: u16,
+    max_pages: u16,
+}
+
+impl Default for WorkdayClient {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl WorkdayClient {
     pub fn new() -> Self {
         WorkdayClient {
-            client: Client::new(),
-            limit: 20,
-            offset: 0,
+            http: HttpClient::default(),
+            page_size: 20,
+            max_pages: 50,
         }
     }
-    pub fn next_offset(&mut self) -> &Self {
-        self.offset += self.limit;
+
+    /// Number of postings requested per page. Workday rejects anything above 20.
+    pub fn with_page_size(mut self, page_size: u16) -> Self {
+        self.page_size = page_size.clamp(1, 20);
+        self
+    }
+
+    /// Upper bound on pages fetched per company, as a guard against runaway tenants.
+    pub fn with_max_pages(mut self, max_pages: u16) -> Self {
+        self.max_pages = max_pages.max(1);
         self
     }
 
-    pub fn change_limit(&mut self, new_limit: u16) -> &Self {
-        self.limit = new_limit;
+    /// Replaces the default HTTP client, e.g. one built from `AppConfig::http`.
+    pub fn with_http_client(mut self, http: HttpClient) -> Self {
+        self.http = http;
         self
     }
 }
 
-//TODO: The url should consider (ignore) locale
-fn workday_to_api_url(company_url: &str) -> ScannerResult<String> {
+/// Tracks the offset into a Workday listing and decides whether another page is needed.
+///
+/// Workday only reports a meaningful `total` on the first page; later pages return 0,
+/// so the first value seen is kept.
+#[derive(Debug)]
+struct PageCursor {
+    limit: u16,
+    offset: u32,
+    total: Option<u32>,
+    pages_left: u16,
+}
+
+impl PageCursor {
+    fn new(limit: u16, max_pages: u16) -> Self {
+        PageCursor {
+            limit,
+            offset: 0,
+            total: None,
+            pages_left: max_pages,
+        }
+    }
+
+    /// Records a fetched page and returns `true` when there is another page to fetch.
+    fn advance(&mut self, page_len: usize, total: i32) -> bool {
+        if self.total.is_none() {
+            self.total = Some(total.max(0) as u32);
+        }
+        self.offset += page_len as u32;
+        self.pages_left = self.pages_left.saturating_sub(1);
+        page_len > 0 && self.pages_left > 0 && self.offset < self.total.unwrap_or(0)
+    }
+}
+
+/// Workday prefixes career site paths with an optional locale such as `en-US` or `fr`.
+fn is_locale(segment: &str) -> bool {
+    let bytes = segment.as_bytes();
+    match bytes.len() {
+        2 => bytes.iter().all(u8::is_ascii_alphabetic),
+        5 => {
+            bytes[2] == b'-'
+                && bytes[..2].iter().all(u8::is_ascii_alphabetic)
+                && bytes[3..].iter().all(u8::is_ascii_alphabetic)
+        }
+        _ => false,
+    }
+}
+
+/// Converts any Workday careers URL into the `/wday/cxs/{tenant}/{site}` API base.
+///
+/// Accepts career site home pages with or without a locale, job detail links, the
+/// `wdN.myworkdaysite.com/recruiting/{tenant}/{site}` variant and already converted API URLs.
+pub fn workday_to_api_url(company_url: &str) -> ScannerResult<String> {
+    let invalid =
+        |reason: &str| ScannerError::SourceUrlError(company_url.to_string(), reason.to_string());
+
     let parsed_url = Url::parse(company_url)?;
-    let domain = parsed_url.domain().ok_or_else(|| {
-        ScannerError::UrlParseError(EmptyHost)
-    })?;
+    let domain = parsed_url.domain().ok_or_else(|| invalid("missing host"))?;
     let host_parts: Vec<&str> = domain.split('.').collect();
-    if host_parts.len() < 3 || !host_parts[1].contains("wd") {
-        println!("host parts does not contain wd");
-        return Err(ScannerError::UrlParseError(EmptyHost));
+    let is_jobs_host = host_parts.len() >= 3 && host_parts[1].starts_with("wd");
+    let is_site_host =
+        host_parts.len() >= 3 && host_parts[0].starts_with("wd") && host_parts[1] == "myworkdaysite";
+    if !is_jobs_host && !is_site_host {
+        return Err(invalid(
+            "host is neither `{tenant}.wdN.myworkdayjobs.com` nor `wdN.myworkdaysite.com`",
+        ));
     }
-    let company = host_parts[0];
-    let wd_env = host_parts[1];
-    let path_segments: Vec<&str> = parsed_url
+
+    let mut path_segments: Vec<&str> = parsed_url
         .path_segments()
-        .ok_or_else(|| ScannerError::UrlParseError(EmptyHost))?
-        .filter(|s| !s.is_empty())
-        .collect();
+        .map(|segments| segments.filter(|s| !s.is_empty()).collect())
+        .unwrap_or_default();
+    // A bare language code is also a valid site name (`/ms/job/...`), so it only counts as
+    // a locale when a site segment, not the job path, follows it.
+    let strip_locale = match path_segments.as_slice() {
+        [first, "job" | "details", ..] => first.len() == 5 && is_locale(first),
+        [first, _, ..] => is_locale(first),
+        _ => false,
+    };
+    if strip_locale {
+        path_segments.remove(0);
+    }
 
-    let career_site = match path_segments.as_slice() {
-        [site] => *site,
-        _ => return Err(ScannerError::UrlParseError(EmptyHost))
+    let (tenant, career_site) = match path_segments.as_slice() {
+        ["wday", "cxs", tenant, site, ..] => (*tenant, *site),
+        ["wday", ..] => return Err(invalid("API path must look like /wday/cxs/{tenant}/{site}")),
+        ["recruiting", tenant, site, ..] if is_site_host => (*tenant, *site),
+        _ if is_site_host => {
+            return Err(invalid("path must look like /recruiting/{tenant}/{site}"));
+        }
+        ["job" | "details", ..] | [] => return Err(invalid("missing career site name in path")),
+        [site, ..] => (host_parts[0], *site),
     };
-    let base = format!("https://{company}.{wd_env}.myworkdayjobs.com");
-    let api_url = format!("{base}/wday/cxs/{company}/{career_site}");
+    let api_url = format!("https://{domain}/wday/cxs/{tenant}/{career_site}");
     Ok(api_url)
 }
 
-#[async_trait]
-impl JobSource for WorkdayClient {
-    async fn get_job_list(&self, company: &Company) -> ScannerResult<Vec<Job>> {
-        let api_url = workday_to_api_url(&company.url)?;
+impl WorkdayClient {
+    async fn fetch_page(&self, url: &str, body: &Value) -> ScannerResult<WorkdayJobPosting> {
+        let resp = self.http.send(self.http.post(url).json(body)).await?;
+        let text = resp.text().await?;
+        serde_json::from_str::<WorkdayJobPosting>(&text).map_err(|err| {
+            let snippet: String = text.chars().take(200).collect();
+            error!("unexpected Workday response from {}: {} ({})", url, err, snippet);
+            err.into()
+        })
+    }
+}
+
+impl WorkdayClient {
+    /// Lists every facet value the tenant behind `company_url` can be filtered by.
+    pub async fn get_facets(&self, company_url: &str) -> ScannerResult<Vec<FacetOption>> {
+        let api_url = workday_to_api_url(company_url)?;
         let url = format!("{}/jobs", api_url);
-        let mut applied_facets = Map::new();
+        let body = serde_json::json!({
+            "appliedFacets": {},
+            "limit": 1,
+            "offset": 0,
+            "searchText": ""
+        });
+        let posts = self.fetch_page(&url, &body).await?;
+        Ok(flatten_facets(&posts.facets))
+    }
 
-        if let Some(loc) = &company.locations {
-            if !loc.is_empty() {
-                applied_facets.insert(
-                    "locations".to_string(),
-                    Value::Array(vec![Value::String(loc.clone())]),
-                );
+    /// Builds `appliedFacets`, looking up ids for any value given by name.
+    async fn applied_facets(&self, company: &Company) -> ScannerResult<Map<String, Value>> {
+        let legacy = [
+            ("locations", &company.locations),
+            ("locationCountry", &company.locationCountry),
+        ]
+        .into_iter()
+        .filter_map(|(parameter, value)| match value {
+            Some(v) if !v.is_empty() => Some((parameter, v)),
+            _ => None,
+        });
+        let requested: Vec<(&str, &String)> = legacy
+            .chain(company.facets.iter().flat_map(|(parameter, values)| {
+                values
+                    .iter()
+                    .filter(|v| !v.is_empty())
+                    .map(move |v| (parameter.as_str(), v))
+            }))
+            .collect();
+
+        let options = if requested.iter().all(|(_, v)| is_facet_id(v)) {
+            Vec::new()
+        } else {
+            self.get_facets(&company.url).await?
+        };
+
+        let mut applied_facets = Map::new();
+        for (parameter, value) in requested {
+            let ids = resolve_facet_value(&options, parameter, value)?;
+            let entry = applied_facets
+                .entry(parameter.to_string())
+                .or_insert_with(|| Value::Array(Vec::new()));
+            if let Value::Array(existing) = entry {
+                for id in ids {
+                    let id = Value::String(id);
+                    if !existing.contains(&id) {
+                        existing.push(id);
+                    }
+                }
             }
         }
+        Ok(applied_facets)
+    }
 
-        if let Some(country) = &company.locationCountry {
-            if !country.is_empty() {
-                applied_facets.insert(
-                    "locationCountry".to_string(),
-                    Value::Array(vec![Value::String(country.clone())]),
-                );
+    /// Pages through every result of a single `searchText` query.
+    async fn search(
+        &self,
+        url: &str,
+        applied_facets: &Map<String, Value>,
+        search_text: &str,
+        company: &Company,
+    ) -> ScannerResult<Vec<Job>> {
+        let today = Local::now().date_naive();
+        let mut jobs = Vec::new();
+        let mut cursor = PageCursor::new(self.page_size, self.max_pages);
+        loop {
+            let body = serde_json::json!({
+                "appliedFacets": Value::Object(applied_facets.clone()),
+                "limit": cursor.limit,
+                "offset": cursor.offset,
+                "searchText": search_text
+            });
+            let posts = self.fetch_page(url, &body).await?;
+            let page_len = posts.jobPostings.len();
+            jobs.extend(posts.jobPostings.iter().map(|j_info| Job {
+                id: j_info
+                    .externalPath
+                    .split('/')
+                    .next_back()
+                    .unwrap_or("")
+                    .to_string(),
+                title: j_info.title.clone(),
+                location: j_info.locationsText.clone(),
+                description: None,
+                company_name: company.name.clone(),
+                external_path: j_info.externalPath.clone(),
+                posted_at: j_info
+                    .postedOn
+                    .as_deref()
+                    .and_then(|p| parse_posted_on(p, today)),
+                requisition_id: j_info.bulletFields.first().cloned(),
+                remote_type: j_info.remoteType.as_deref().and_then(RemoteType::from_label),
+                ..Default::default()
+            }));
+            if !cursor.advance(page_len, posts.total) {
+                break;
             }
         }
+        Ok(jobs)
+    }
+}
 
-        let body = serde_json::json!({
-            "appliedFacets": Value::Object(applied_facets), 
-            "limit": self.limit,
-            "offset": self.offset,
-            "searchText": ""
-        });
-        let resp = self.client.post(&url).json(&body).send().await?;
-        match resp.json::<WorkdayJobPosting>().await {
-            Ok(posts) => {
-                let jobs = posts
-                    .jobPostings
-                    .iter()
-                    .map(|j_info| Job {
-                        id: j_info
-                            .externalPath
-                            .split('/')
-                            .last()
-                            .unwrap_or("")
-                            .to_string(),
-                        title: j_info.title.clone(),
-                        location: j_info.locationsText.clone(),
-                        description: None,
-                        company_name: company.name.clone(),
-                        external_path: j_info.externalPath.clone(),
-                    })
-                    .collect();
-                Ok(jobs)
-            }
-            Err(err) => {
-                let resp2 = self.client.post(&url).json(&body).send().await?;
-                let r = resp2.bytes().await?;
-                println!("err {} {:?}", err, r);
-                Err(ScannerError::ApiError(err))
-            },
+#[async_trait]
+impl JobSource for WorkdayClient {
+    /// Runs every configured search query (or a single empty one) and returns the union
+    /// of the results, deduplicated by job id.
+    async fn get_job_list(&self, company: &Company) -> ScannerResult<Vec<Job>> {
+        let api_url = workday_to_api_url(&company.url)?;
+        let url = format!("{}/jobs", api_url);
+        let applied_facets = self.applied_facets(company).await?;
+
+        let queries: Vec<&str> = if company.search_queries.is_empty() {
+            vec![""]
+        } else {
+            company.search_queries.iter().map(String::as_str).collect()
+        };
+
+        let mut seen = HashSet::new();
+        let mut jobs = Vec::new();
+        for query in queries {
+            let found = self.search(&url, &applied_facets, query, company).await?;
+            jobs.extend(found.into_iter().filter(|job| seen.insert(job.id.clone())));
         }
+        Ok(jobs)
     }
 
-    async fn get_job_details(&self, job: &Job, company_url: &str) -> ScannerResult<String> {
-        let api_url = workday_to_api_url(company_url)?;
+    async fn get_job_details(&self, job: &Job, company: &Company) -> ScannerResult<Job> {
+        let api_url = workday_to_api_url(&company.url)?;
         let url = format!("{}{}", api_url, job.external_path);
-        let resp = self.client.get(&url).send().await?;
+        let resp = self.http.send(self.http.get(&url)).await?;
         let text = resp.text().await?;
         let job_des = serde_json::from_str::<JobPostingDetails>(&text)?;
-        Ok(job_des.jobPostingInfo.jobDescription)
+        Ok(job_des.apply_to(job))
     }
 }
 
@@ -179,7 +521,7 @@ mod tests {
                }
           }
             "#;
-        let result = serde_json::from_str::<JobPostingDetails>(&json_struct);
+        let result = serde_json::from_str::<JobPostingDetails>(json_struct);
         assert!(result.is_ok());
         let job_posting = result.unwrap();
         assert_eq!(
@@ -188,6 +530,193 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_details_fill_job_metadata() {
+        let json_struct = r#"
+            {
+                "jobPostingInfo": {
+                    "title": "Associate - Software Engineering",
+                    "jobDescription": "Some job description",
+                    "location": "Pune",
+                    "postedOn": "Posted 3 Days Ago",
+                    "startDate": "2024-05-01",
+                    "timeType": "Full time",
+                    "jobReqId": "R123456",
+                    "remoteType": "Hybrid",
+                    "country": { "descriptor": "India", "id": "c4f78be1a8f14da0ab49ce1162348a5e" }
+                },
+                "hiringOrganization": { "descriptor": "Morgan Stanley Advantage Services", "url": "" }
+            }
+            "#;
+        let listed = Job {
+            id: "R123456".to_string(),
+            title: "Associate - Software Engineering".to_string(),
+            location: "2 Locations".to_string(),
+            external_path: "/job/Pune/Associate_R123456".to_string(),
+            company_name: "Morgan Stanley".to_string(),
+            ..Default::default()
+        };
+        let details = serde_json::from_str::<JobPostingDetails>(json_struct).unwrap();
+        let job = details.apply_to(&listed);
+
+        assert_eq!(job.description.as_deref(), Some("Some job description"));
+        assert_eq!(job.location, "Pune");
+        assert_eq!(job.posted_at, NaiveDate::from_ymd_opt(2024, 5, 1));
+        assert_eq!(job.requisition_id.as_deref(), Some("R123456"));
+        assert_eq!(job.employment_type, Some(EmploymentType::FullTime));
+        assert_eq!(job.remote_type, Some(RemoteType::Hybrid));
+        assert_eq!(job.country.as_deref(), Some("India"));
+        assert_eq!(
+            job.hiring_organization.as_deref(),
+            Some("Morgan Stanley Advantage Services")
+        );
+        assert_eq!(job.external_path, listed.external_path);
+    }
+
+    #[test]
+    fn test_page_cursor_walks_until_total() {
+        let mut cursor = PageCursor::new(20, 50);
+        assert!(cursor.advance(20, 45));
+        assert_eq!(cursor.offset, 20);
+        // later pages report a total of 0, the first total must win
+        assert!(cursor.advance(20, 0));
+        assert_eq!(cursor.offset, 40);
+        assert!(!cursor.advance(5, 0));
+    }
+
+    #[test]
+    fn test_page_cursor_stops_on_empty_page_and_max_pages() {
+        let mut cursor = PageCursor::new(20, 50);
+        assert!(!cursor.advance(0, 100));
+
+        let mut cursor = PageCursor::new(20, 2);
+        assert!(cursor.advance(20, 100));
+        assert!(!cursor.advance(20, 100));
+    }
+
+    fn sample_facets() -> Vec<WorkdayFacet> {
+        let json = r#"
+            [
+                {
+                    "facetParameter": "locationMainGroup",
+                    "values": [
+                        {
+                            "facetParameter": "locationCountry",
+                            "descriptor": "Country",
+                            "values": [
+                                { "descriptor": "India", "id": "c4f78be1a8f14da0ab49ce1162348a5e", "count": 12 },
+                                { "descriptor": "United States of America", "id": "bc33aa3152ec42d4995f4791a106ed09", "count": 40 }
+                            ]
+                        }
+                    ]
+                },
+                {
+                    "facetParameter": "locations",
+                    "descriptor": "Locations",
+                    "values": [
+                        { "descriptor": "Pune - Hinjewadi", "id": "430f6a41665e10014f51cd6200820000", "count": 3 },
+                        { "descriptor": "Pune - Kharadi", "id": "7edfb62955d310014b6351f083a60000", "count": 2 },
+                        { "descriptor": "Bengaluru", "id": "6a30583342834bee9099431bfefc5ff7", "count": 5 }
+                    ]
+                }
+            ]
+        "#;
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn test_flatten_facets_uses_nearest_parameter() {
+        let options = flatten_facets(&sample_facets());
+        assert_eq!(options.len(), 5);
+        assert_eq!(
+            options[0],
+            FacetOption {
+                parameter: "locationCountry".to_string(),
+                id: "c4f78be1a8f14da0ab49ce1162348a5e".to_string(),
+                descriptor: "India".to_string(),
+                count: 12,
+            }
+        );
+        assert_eq!(options[2].parameter, "locations");
+    }
+
+    #[test]
+    fn test_resolve_facet_value() {
+        let options = flatten_facets(&sample_facets());
+        assert_eq!(
+            resolve_facet_value(&options, "locationCountry", "india").unwrap(),
+            vec!["c4f78be1a8f14da0ab49ce1162348a5e"]
+        );
+        assert_eq!(
+            resolve_facet_value(&options, "locations", "Pune").unwrap(),
+            vec![
+                "430f6a41665e10014f51cd6200820000",
+                "7edfb62955d310014b6351f083a60000"
+            ]
+        );
+        // raw ids pass through untouched
+        assert_eq!(
+            resolve_facet_value(&[], "locations", "7edfb62955d310014b6351f083a60000").unwrap(),
+            vec!["7edfb62955d310014b6351f083a60000"]
+        );
+        assert!(resolve_facet_value(&options, "locations", "India").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_applied_facets_merges_legacy_and_generic_facets() {
+        let company = Company {
+            name: "test".to_string(),
+            url: "https://ms.wd5.myworkdayjobs.com/External".to_string(),
+            locations: Some("430f6a41665e10014f51cd6200820000".to_string()),
+            facets: [
+                (
+                    "locations".to_string(),
+                    vec![
+                        "430f6a41665e10014f51cd6200820000".to_string(),
+                        "7edfb62955d310014b6351f083a60000".to_string(),
+                    ],
+                ),
+                (
+                    "timeType".to_string(),
+                    vec!["9d4a0d1e0f1c4e2b8a6b5c4d3e2f1a0b".to_string()],
+                ),
+            ]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        };
+        let applied = WorkdayClient::new().applied_facets(&company).await.unwrap();
+        assert_eq!(
+            Value::Object(applied),
+            serde_json::json!({
+                "locations": [
+                    "430f6a41665e10014f51cd6200820000",
+                    "7edfb62955d310014b6351f083a60000"
+                ],
+                "timeType": ["9d4a0d1e0f1c4e2b8a6b5c4d3e2f1a0b"]
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_posted_on() {
+        let today = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let cases = [
+            ("Posted Today", Some((2024, 3, 1))),
+            ("Posted Yesterday", Some((2024, 2, 29))),
+            ("Posted 1 Day Ago", Some((2024, 2, 29))),
+            ("Posted 3 Days Ago", Some((2024, 2, 27))),
+            ("Posted 30+ Days Ago", Some((2024, 1, 31))),
+            ("posted 7 days ago ", Some((2024, 2, 23))),
+            ("Posted a while back", None),
+            ("", None),
+        ];
+        for (text, expected) in cases {
+            let expected = expected.and_then(|(y, m, d)| NaiveDate::from_ymd_opt(y, m, d));
+            assert_eq!(parse_posted_on(text, today), expected, "{text}");
+        }
+    }
+
     #[test]
     fn test_parse_home_to_api_url() {
         match workday_to_api_url("https://athenahealth.wd1.myworkdayjobs.com/External") {
@@ -205,4 +734,53 @@ mod tests {
             Ok(res) => assert_eq!(res,"https://ms.wd5.myworkdayjobs.com/wday/cxs/ms/External")
         };
     }
+
+    #[test]
+    fn test_parse_localized_and_deep_urls_to_api_url() {
+        let expected = "https://foo.wd5.myworkdayjobs.com/wday/cxs/foo/External";
+        for url in [
+            "https://foo.wd5.myworkdayjobs.com/en-US/External",
+            "https://foo.wd5.myworkdayjobs.com/fr/External/",
+            "https://foo.wd5.myworkdayjobs.com/en-US/External/job/Pune/Software-Engineer_R123?source=x",
+            "https://foo.wd5.myworkdayjobs.com/External/details/Software-Engineer_R123",
+            "https://foo.wd5.myworkdayjobs.com/wday/cxs/foo/External",
+            "https://foo.wd5.myworkdayjobs.com/wday/cxs/foo/External/jobs",
+        ] {
+            assert_eq!(workday_to_api_url(url).unwrap(), expected, "{url}");
+        }
+
+        assert_eq!(
+            workday_to_api_url("https://wd3.myworkdaysite.com/en-US/recruiting/bar/Careers")
+                .unwrap(),
+            "https://wd3.myworkdaysite.com/wday/cxs/bar/Careers"
+        );
+        // a two letter site name is not mistaken for a locale
+        assert_eq!(
+            workday_to_api_url("https://x.wd1.myworkdayjobs.com/ms/job/Pune/Eng_R1").unwrap(),
+            "https://x.wd1.myworkdayjobs.com/wday/cxs/x/ms"
+        );
+        assert_eq!(
+            workday_to_api_url("https://x.wd1.myworkdayjobs.com/en/ms/job/Pune/Eng_R1").unwrap(),
+            "https://x.wd1.myworkdayjobs.com/wday/cxs/x/ms"
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_urls_reports_reason() {
+        for url in [
+            "https://example.com/careers",
+            "https://foo.wd5.myworkdayjobs.com/",
+            "https://foo.wd5.myworkdayjobs.com/en-US/job/Pune/Engineer_R1",
+            "https://foo.wd5.myworkdayjobs.com/wday/foo",
+            "https://wd3.myworkdaysite.com/Careers",
+        ] {
+            match workday_to_api_url(url) {
+                Err(ScannerError::SourceUrlError(bad, reason)) => {
+                    assert_eq!(bad, url);
+                    assert!(!reason.is_empty());
+                }
+                other => panic!("expected SourceUrlError for {url}, got {other:?}"),
+            }
+        }
+    }
 }
@@ -1,3 +1,90 @@
-fn main() {
-    panic!("cli is yet to be implemented");
+use job_scanner::adapters::file_source::write_snapshot;
+use job_scanner::adapters::source_detector::SourceDetector;
+use job_scanner::adapters::sqlite_storage::SqliteStorage;
+use job_scanner::adapters::source_router::SourceRouter;
+use job_scanner::adapters::workday_client::WorkdayClient;
+use job_scanner::config::AppConfig;
+use job_scanner::domain::job_source::JobSource;
+use job_scanner::error::ScannerResult;
+use std::path::Path;
+
+const USAGE: &str = "usage: cli facets <workday careers url>
+       cli snapshot <company name> <output.ndjson>
+       cli detect <careers url> [company name]
+       cli filter-stats";
+
+#[tokio::main]
+async fn main() -> ScannerResult<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.iter().map(String::as_str).collect::<Vec<_>>().as_slice() {
+        ["facets", url] => list_facets(url).await,
+        ["snapshot", company, output] => snapshot(company, Path::new(output)).await,
+        ["detect", url] => detect(url, None).await,
+        ["detect", url, company] => detect(url, Some(company)).await,
+        ["filter-stats"] => filter_stats().await,
+        _ => {
+            eprintln!("{USAGE}");
+            std::process::exit(2);
+        }
+    }
+}
+
+/// Prints every facet value of a Workday tenant, grouped by the config key it belongs to.
+async fn list_facets(url: &str) -> ScannerResult<()> {
+    let client = WorkdayClient::new();
+    let options = client.get_facets(url).await?;
+
+    let mut current = "";
+    for option in &options {
+        if option.parameter != current {
+            current = &option.parameter;
+            println!("\n{current}:");
+        }
+        println!(
+            "  {:<40} {}  ({} jobs)",
+            option.descriptor, option.id, option.count
+        );
+    }
+    Ok(())
+}
+
+/// Captures the current postings of a configured company, descriptions included, to an
+/// NDJSON file that a `"source": "file"` company can replay.
+async fn snapshot(company_name: &str, output: &Path) -> ScannerResult<()> {
+    let settings = AppConfig::new()?;
+    let Some(company) = settings.companies.iter().find(|c| c.name == company_name) else {
+        eprintln!("no company named `{company_name}` in data/companies.json");
+        std::process::exit(2);
+    };
+    let source = SourceRouter::new(&settings.http)?;
+    let mut jobs = Vec::new();
+    for job in source.get_job_list(company).await? {
+        jobs.push(source.get_job_details(&job, company).await?);
+    }
+    write_snapshot(output, &jobs)?;
+    println!("Wrote {} jobs to {}", jobs.len(), output.display());
+    Ok(())
+}
+
+/// Prints a `data/companies.json` entry for the job source behind a careers page.
+async fn detect(url: &str, company_name: Option<&str>) -> ScannerResult<()> {
+    let mut detection = SourceDetector::new().detect(url).await?;
+    if let Some(name) = company_name {
+        detection.company.name = name.to_string();
+    }
+    eprintln!("Detected {:?} from {}", detection.company.source, detection.evidence);
+    println!("{}", serde_json::to_string_pretty(&detection.company)?);
+    Ok(())
+}
+
+/// Prints how many title filter answers of each model named jobs it was never shown.
+async fn filter_stats() -> ScannerResult<()> {
+    let storage = SqliteStorage::new("job_scanner.db")?;
+    for stats in storage.filter_stats().await? {
+        println!(
+            "{:<40} {:>6} ids returned, {:>6} rejected",
+            stats.model, stats.returned_ids, stats.rejected_ids
+        );
+    }
+    Ok(())
 }
@@ -1,6 +1,6 @@
-use job_scanner::adapters::ollama::OllamaProvider;
+use job_scanner::adapters::llm::provider_from_config;
+use job_scanner::adapters::source_router::SourceRouter;
 use job_scanner::adapters::sqlite_storage::SqliteStorage;
-use job_scanner::adapters::workday_client::WorkdayClient;
 use job_scanner::{config::AppConfig, core::processor, error::ScannerResult};
 use std::time::Duration;
 use tracing::error;
@@ -13,17 +13,18 @@ async fn main() -> ScannerResult<()> {
     let bytes = std::fs::read(settings.resume_path).unwrap();
     let resume_content: String = pdf_extract::extract_text_from_mem(&bytes).unwrap();
 
-    let workday_client = std::sync::Arc::new(WorkdayClient::new());
-    let ollama_provider = std::sync::Arc::new(OllamaProvider::new(settings.llm.model_name));
+    let llm_provider = provider_from_config(&settings.llm)?;
+    let job_source =
+        std::sync::Arc::new(SourceRouter::new(&settings.http)?.with_llm(llm_provider.clone()));
     let storage = std::sync::Arc::new(SqliteStorage::new("job_scanner.db")?);
 
     loop {
         for company in &settings.companies {
             if let Err(e) = processor::process_company(
-                &company,
+                company,
                 &resume_content,
-                workday_client.clone(),
-                ollama_provider.clone(),
+                job_source.clone(),
+                llm_provider.clone(),
                 storage.clone(),
             )
             .await
@@ -1,3 +1,4 @@
+use crate::adapters::http::HttpConfig;
 use crate::{core::model::Company, error::ScannerResult};
 use serde::{Deserialize, Serialize};
 use std::fs::{self, read_to_string};
@@ -8,12 +9,36 @@ pub struct AppConfig {
     pub resume_path: String,
     pub llm: LLMChoice,
     pub companies: Vec<Company>,
+    #[serde(default)]
+    pub http: HttpConfig,
+}
+
+/// Which kind of server `LLMChoice::model_name` is served by.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum LLMBackend {
+    #[default]
+    Ollama,
+    /// vLLM, llama.cpp server, LM Studio or anything else speaking `/v1/chat/completions`.
+    OpenaiCompatible,
+    /// The Anthropic Messages API; the key is read from `ANTHROPIC_API_KEY` by default.
+    Anthropic,
 }
 
 #[allow(dead_code)]
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct LLMChoice {
     pub model_name: String,
+    #[serde(default)]
+    pub provider: LLMBackend,
+    /// Server root, e.g. `http://localhost:8000/v1`; required for `openai_compatible`.
+    #[serde(default)]
+    pub base_url: Option<String>,
+    /// Environment variable holding the API key, for servers that want one.
+    #[serde(default)]
+    pub api_key_env: Option<String>,
+    #[serde(default)]
+    pub temperature: Option<f32>,
 }
 
 #[allow(dead_code)]
@@ -23,8 +48,13 @@ impl Default for AppConfig {
             resume_path: "".to_string(),
             llm: LLMChoice {
                 model_name: "mistral:latest".to_string(),
+                provider: LLMBackend::Ollama,
+                base_url: None,
+                api_key_env: None,
+                temperature: None,
             },
             companies: vec![],
+            http: HttpConfig::default(),
         }
     }
 }
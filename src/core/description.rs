@@ -0,0 +1,299 @@
+/// Phrases that mark the equal-opportunity / legal paragraphs appended to most postings.
+const BOILERPLATE_MARKERS: [&str; 7] = [
+    "equal opportunity employer",
+    "equal employment opportunity",
+    "without regard to race",
+    "regardless of race",
+    "affirmative action",
+    "protected veteran",
+    "e-verify",
+];
+
+/// Turns a job description (HTML or plain text) into compact, Markdown flavoured text:
+/// tags and attributes are dropped, lists become `- ` bullets, entities are decoded,
+/// whitespace is collapsed and EEO boilerplate sentences are removed.
+///
+/// Entity-escaped HTML (`&lt;p&gt;...`, as served by Greenhouse) is unescaped first.
+pub fn normalize_description(raw: &str) -> String {
+    let text = if looks_like_html(raw) {
+        html_to_text(raw)
+    } else {
+        let decoded = decode_entities(raw);
+        if looks_like_html(&decoded) {
+            html_to_text(&decoded)
+        } else {
+            decoded
+        }
+    };
+    tidy(&text)
+}
+
+fn looks_like_html(raw: &str) -> bool {
+    let mut rest = raw;
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start + 1..];
+        let name = rest.trim_start_matches('/');
+        if name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '!') {
+            return true;
+        }
+    }
+    false
+}
+
+fn html_to_text(html: &str) -> String {
+    let mut out = String::with_capacity(html.len() / 2);
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        push_text(&mut out, &rest[..start]);
+        rest = &rest[start..];
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+        let Some(end) = rest.find('>') else {
+            break;
+        };
+        let tag = &rest[1..end];
+        rest = &rest[end + 1..];
+
+        let closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .next()
+            .unwrap_or("")
+            .to_ascii_lowercase();
+        match name.as_str() {
+            "script" | "style" if !closing => {
+                let close = format!("</{name}");
+                rest = rest
+                    .to_ascii_lowercase()
+                    .find(&close)
+                    .map_or("", |idx| &rest[idx..]);
+            }
+            "br" => out.push('\n'),
+            "li" if !closing => out.push_str("\n- "),
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" if !closing => out.push_str("\n\n## "),
+            "td" | "th" => out.push(' '),
+            "p" | "div" | "ul" | "ol" | "section" | "article" | "table" | "tr" | "blockquote"
+            | "header" | "footer" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "hr" => {
+                out.push_str("\n\n")
+            }
+            _ => {}
+        }
+    }
+    push_text(&mut out, rest);
+    out
+}
+
+/// Appends HTML text content; source line breaks carry no meaning in HTML.
+fn push_text(out: &mut String, text: &str) {
+    if text.is_empty() {
+        return;
+    }
+    let decoded = decode_entities(text);
+    let mut last_space = out.ends_with(char::is_whitespace);
+    for c in decoded.chars() {
+        if c.is_whitespace() {
+            if !last_space {
+                out.push(' ');
+                last_space = true;
+            }
+        } else {
+            out.push(c);
+            last_space = false;
+        }
+    }
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let decoded = rest[1..]
+            .find(';')
+            .filter(|&end| end <= 10)
+            .and_then(|end| decode_entity(&rest[1..=end]).map(|c| (c, end + 2)));
+        match decoded {
+            Some((c, len)) => {
+                out.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn decode_entity(entity: &str) -> Option<char> {
+    if let Some(number) = entity.strip_prefix('#') {
+        let code = match number.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => number.parse().ok()?,
+        };
+        return char::from_u32(code).map(|c| if c == '\u{a0}' { ' ' } else { c });
+    }
+    let c = match entity {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => ' ',
+        "ndash" => '–',
+        "mdash" => '—',
+        "lsquo" => '‘',
+        "rsquo" => '’',
+        "ldquo" => '“',
+        "rdquo" => '”',
+        "hellip" => '…',
+        "bull" => '•',
+        "middot" => '·',
+        "copy" => '©',
+        "reg" => '®',
+        "trade" => '™',
+        _ => return None,
+    };
+    Some(c)
+}
+
+/// Collapses whitespace line by line, squeezes blank lines and drops boilerplate sentences.
+///
+/// If every sentence turns out to be boilerplate the collapsed text is returned as is,
+/// so a non-empty description never comes back empty.
+fn tidy(text: &str) -> String {
+    let mut paragraphs: Vec<Vec<String>> = Vec::new();
+    let mut current: Vec<String> = Vec::new();
+    for line in text.lines() {
+        let line = line.split_whitespace().collect::<Vec<_>>().join(" ");
+        if line.is_empty() {
+            if !current.is_empty() {
+                paragraphs.push(std::mem::take(&mut current));
+            }
+        } else if line != "-" && line != "##" {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        paragraphs.push(current);
+    }
+
+    let join = |paragraphs: Vec<Vec<String>>| {
+        paragraphs
+            .into_iter()
+            .map(|lines| lines.join("\n"))
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    };
+    let stripped: Vec<Vec<String>> = paragraphs
+        .iter()
+        .map(|lines| {
+            lines
+                .iter()
+                .map(|line| strip_boilerplate(line))
+                .filter(|line| !line.is_empty())
+                .collect::<Vec<_>>()
+        })
+        .filter(|lines| !lines.is_empty())
+        .collect();
+    if stripped.is_empty() {
+        join(paragraphs)
+    } else {
+        join(stripped)
+    }
+}
+
+/// Removes the sentences of a single line that contain a boilerplate marker.
+fn strip_boilerplate(line: &str) -> String {
+    sentences(line)
+        .filter(|sentence| {
+            let lower = sentence.to_lowercase();
+            !BOILERPLATE_MARKERS.iter().any(|marker| lower.contains(marker))
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Splits a whitespace-collapsed line after `.`, `!` or `?` followed by a space.
+fn sentences(line: &str) -> impl Iterator<Item = &str> {
+    let mut rest = line;
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+        let end = rest
+            .match_indices(['.', '!', '?'])
+            .map(|(i, _)| i + 1)
+            .find(|&i| rest[i..].starts_with(' '))
+            .unwrap_or(rest.len());
+        let (sentence, tail) = rest.split_at(end);
+        rest = tail.trim_start();
+        Some(sentence)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_html_description_becomes_markdown_text() {
+        let html = r#"<p style="margin:0"><b>About&nbsp;the   role</b></p>
+            <p>We&rsquo;re hiring a <span style="color:red">Rust</span> engineer &amp; mentor.</p>
+            <h2>Requirements</h2>
+            <ul><li>4+ years of Java</li><li> React &lt;3 </li></ul>
+            <script>var tracking = "<p>nope</p>";</script>
+            <!-- internal note -->
+            <p>Line one<br/>Line two &#8211; done&#x21;</p>"#;
+        assert_eq!(
+            normalize_description(html),
+            "About the role\n\n\
+             We’re hiring a Rust engineer & mentor.\n\n\
+             ## Requirements\n\n\
+             - 4+ years of Java\n- React <3\n\n\
+             Line one\nLine two – done!"
+        );
+    }
+
+    #[test]
+    fn test_boilerplate_paragraphs_are_removed() {
+        let html = "<p>Build payment systems.</p>\
+            <p>Acme is an Equal Opportunity Employer. All qualified applicants will receive \
+            consideration without regard to race, color, religion or sex.</p>";
+        assert_eq!(normalize_description(html), "Build payment systems.");
+    }
+
+    #[test]
+    fn test_boilerplate_sentences_are_removed_from_single_paragraph() {
+        let text = "Build payment systems in Rust. You will own the ledger! \
+            Acme is an equal opportunity employer and participates in E-Verify.";
+        assert_eq!(
+            normalize_description(text),
+            "Build payment systems in Rust. You will own the ledger!"
+        );
+        let only_boilerplate = "Acme is an Equal Opportunity Employer.";
+        assert_eq!(normalize_description(only_boilerplate), only_boilerplate);
+    }
+
+    #[test]
+    fn test_escaped_html_is_unescaped_first() {
+        let escaped = "&lt;p&gt;Ship &amp;amp; iterate&lt;/p&gt;&lt;ul&gt;&lt;li&gt;Go&lt;/li&gt;&lt;/ul&gt;";
+        assert_eq!(normalize_description(escaped), "Ship & iterate\n\n- Go");
+    }
+
+    #[test]
+    fn test_plain_text_keeps_line_breaks() {
+        let text = "Senior   engineer\n\n\n\nSalary: 100 &amp; up\nRemote if x < 3";
+        assert_eq!(
+            normalize_description(text),
+            "Senior engineer\n\nSalary: 100 & up\nRemote if x < 3"
+        );
+    }
+}
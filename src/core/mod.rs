@@ -1,2 +1,3 @@
+pub mod description;
 pub mod model;
 pub mod processor;
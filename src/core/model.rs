@@ -1,16 +1,143 @@
+use chrono::NaiveDate;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+/// The applicant tracking system a company publishes its openings through.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SourceKind {
+    #[default]
+    Workday,
+    Greenhouse,
+    Lever,
+    Ashby,
+    #[serde(rename = "smartrecruiters")]
+    SmartRecruiters,
+    Workable,
+    OracleHcm,
+    #[serde(rename = "successfactors")]
+    SuccessFactors,
+    JsonLd,
+    Feed,
+    HackerNews,
+    /// Jobs captured to local JSON/NDJSON files.
+    File,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[allow(non_snake_case)]
 pub struct Company {
     pub name: String,
+    /// Careers page of the company on its `source`, e.g. `https://boards.greenhouse.io/acme`.
     pub url: String,
+    #[serde(default)]
+    pub source: SourceKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub locations: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub locationCountry: Option<String>,
+    /// Extra facets (Workday `jobFamilyGroup`, `timeType`, ...; Oracle `locations`,
+    /// `workplaceTypes`, ...) mapped to the ids or names of the values to keep.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub facets: BTreeMap<String, Vec<String>>,
+    /// Keyword searches run server side; results of all queries are merged.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub search_queries: Vec<String>,
+    /// Postings older than this are dropped before they reach the LLM.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_age_days: Option<u32>,
+    /// CSS selector for the posting links on a `json_ld` listing page, e.g. `a.job-link`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub link_selector: Option<String>,
+    /// Upper bound on detail pages fetched per scan of a `json_ld` listing page.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_links: Option<usize>,
+}
+
+#[derive(JsonSchema, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmploymentType {
+    FullTime,
+    PartTime,
+    Contract,
+    Temporary,
+    Internship,
+}
+
+impl EmploymentType {
+    /// Maps the free-form labels job boards use ("Full time", "Part-Time", "Intern", ...).
+    pub fn from_label(label: &str) -> Option<Self> {
+        let label = label.to_lowercase();
+        if label.contains("full") || label.contains("permanent") || label.contains("regular") {
+            Some(EmploymentType::FullTime)
+        } else if label.contains("part") {
+            Some(EmploymentType::PartTime)
+        } else if label.contains("contract") || label.contains("freelance") {
+            Some(EmploymentType::Contract)
+        } else if label.contains("temp") || label.contains("fixed term") {
+            Some(EmploymentType::Temporary)
+        } else if label.contains("intern") || label.contains("apprentice") {
+            Some(EmploymentType::Internship)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(JsonSchema, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RemoteType {
+    OnSite,
+    Hybrid,
+    Remote,
+}
+
+impl RemoteType {
+    pub fn from_label(label: &str) -> Option<Self> {
+        let label = label.to_lowercase();
+        if label.contains("hybrid") || label.contains("flexible") {
+            Some(RemoteType::Hybrid)
+        } else if label.contains("remote") {
+            Some(RemoteType::Remote)
+        } else if label.contains("site") || label.contains("office") {
+            Some(RemoteType::OnSite)
+        } else {
+            None
+        }
+    }
+}
+
+/// Published pay range of a posting.
+#[derive(JsonSchema, Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct Compensation {
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub currency: Option<String>,
+    /// Pay period as published, e.g. `YEAR`, `MONTH`, `HOUR`.
+    pub interval: Option<String>,
+    /// Human readable summary, e.g. "$81K – $87K • 0.5% – 1.75%".
+    pub summary: Option<String>,
+}
+
+impl Compensation {
+    pub fn describe(&self) -> String {
+        if let Some(summary) = &self.summary {
+            return summary.clone();
+        }
+        let range = match (self.min, self.max) {
+            (Some(min), Some(max)) if min != max => format!("{min}-{max}"),
+            (Some(value), _) | (None, Some(value)) => value.to_string(),
+            (None, None) => String::new(),
+        };
+        [Some(range), self.currency.clone(), self.interval.as_ref().map(|i| format!("per {i}"))]
+            .into_iter()
+            .flatten()
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
 }
 
-#[derive(JsonSchema, Serialize, Deserialize, Debug, Clone)]
+#[derive(JsonSchema, Serialize, Deserialize, Debug, Clone, Default)]
 pub struct Job {
     pub id: String,
     pub title: String,
@@ -18,16 +145,119 @@ pub struct Job {
     pub description: Option<String>,
     pub external_path: String,
     pub company_name: String,
+    pub posted_at: Option<NaiveDate>,
+    /// Last day the posting accepts applications, when the source publishes one.
+    pub valid_through: Option<NaiveDate>,
+    pub requisition_id: Option<String>,
+    pub employment_type: Option<EmploymentType>,
+    pub remote_type: Option<RemoteType>,
+    pub country: Option<String>,
+    pub hiring_organization: Option<String>,
+    pub department: Option<String>,
+    pub compensation: Option<Compensation>,
+}
+
+impl Job {
+    /// Whether the posting is known to be older than `max_age_days` on `today`.
+    /// Jobs without a posting date are never considered stale.
+    pub fn is_stale(&self, max_age_days: u32, today: NaiveDate) -> bool {
+        self.posted_at
+            .is_some_and(|posted_at| (today - posted_at).num_days() > i64::from(max_age_days))
+    }
+
+    /// Whether the posting stopped accepting applications before `today`.
+    pub fn is_expired(&self, today: NaiveDate) -> bool {
+        self.valid_through.is_some_and(|valid_through| valid_through < today)
+    }
+
+    /// Link to the posting; sources that only know a path are joined onto `company_url`.
+    pub fn apply_url(&self, company_url: &str) -> String {
+        if self.external_path.starts_with("http://") || self.external_path.starts_with("https://")
+        {
+            self.external_path.clone()
+        } else {
+            format!("{}{}", company_url, self.external_path)
+        }
+    }
+
+    /// Renders the job as one line of a `LLMProvider::filter_titles` batch:
+    /// `id: title (location)`.
+    pub fn title_line(&self) -> String {
+        if self.location.is_empty() {
+            format!("{}: {}", self.id, self.title)
+        } else {
+            format!("{}: {} ({})", self.id, self.title, self.location)
+        }
+    }
+
+    /// Renders the job as the text handed to `LLMProvider::analyze_match`: the known
+    /// metadata as a short header followed by the description.
+    pub fn prompt_text(&self) -> String {
+        let mut text = format!("Title: {}\nLocation: {}\n", self.title, self.location);
+        if let Some(country) = &self.country {
+            text.push_str(&format!("Country: {country}\n"));
+        }
+        if let Some(organization) = &self.hiring_organization {
+            text.push_str(&format!("Hiring organization: {organization}\n"));
+        }
+        if let Some(department) = &self.department {
+            text.push_str(&format!("Department: {department}\n"));
+        }
+        if let Some(employment_type) = self.employment_type {
+            text.push_str(&format!("Employment type: {employment_type:?}\n"));
+        }
+        if let Some(remote_type) = self.remote_type {
+            text.push_str(&format!("Workplace: {remote_type:?}\n"));
+        }
+        if let Some(compensation) = &self.compensation {
+            text.push_str(&format!("Compensation: {}\n", compensation.describe()));
+        }
+        if let Some(posted_at) = self.posted_at {
+            text.push_str(&format!("Posted on: {posted_at}\n"));
+        }
+        if let Some(description) = &self.description {
+            text.push('\n');
+            text.push_str(description);
+        }
+        text
+    }
 }
 
+/// Ids of the jobs in a `filter_titles` batch that the model considers a match.
 #[derive(JsonSchema, Deserialize, Debug)]
 pub struct MatchingJobTitles {
-    pub matching_jobs: Vec<Job>,
+    /// Ids of the matching jobs, exactly as listed.
+    pub matching_jobs: Vec<String>,
+}
+
+/// How often a model answered `filter_titles` with ids that were not in its input.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ModelFilterStats {
+    pub model: String,
+    pub returned_ids: u64,
+    pub rejected_ids: u64,
+}
+
+/// The facts an LLM pulls out of a free-form posting such as a Hacker News comment.
+#[derive(JsonSchema, Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct ExtractedPosting {
+    /// The hiring company.
+    pub company: String,
+    /// The role, or roles separated by ", ".
+    pub title: String,
+    /// Where the job is based.
+    pub location: String,
+    /// Remote policy, null when not stated.
+    pub remote_type: Option<RemoteType>,
 }
 
 #[derive(JsonSchema, Deserialize, Debug)]
 pub struct LlmAnalysis {
+    /// Whether the job suits the resume.
     pub is_match: bool,
+    /// How good the match is, from 0 to 100.
+    #[schemars(range(min = 0, max = 100))]
     pub score: u8,
+    /// A brief, one-paragraph explanation of the decision.
     pub reasoning: String,
 }
@@ -1,7 +1,76 @@
-use crate::core::model::Company;
+use crate::core::description::normalize_description;
+use crate::core::model::{Company, Job};
 use crate::domain::{job_source::JobSource, llm::LLMProvider, storage::Storage};
 use crate::error::ScannerResult;
+use chrono::Local;
+use std::collections::HashSet;
 use std::sync::Arc;
+use tracing::warn;
+
+/// Token budget for the job lines of one `filter_titles` call, leaving room in a 10k
+/// context for the instructions, the profile summary and the answer.
+pub const TITLE_BATCH_TOKENS: usize = 2_000;
+
+/// Rough token count of `text`; about four characters per token for English text.
+fn estimate_tokens(text: &str) -> usize {
+    text.chars().count().div_ceil(4)
+}
+
+/// Splits `jobs` into consecutive batches of `Job::title_line`s that each fit in
+/// `max_tokens`. A line longer than the budget gets a batch of its own.
+pub fn title_batches(jobs: &[Job], max_tokens: usize) -> Vec<Vec<String>> {
+    let mut batches: Vec<Vec<String>> = Vec::new();
+    let mut used = 0;
+    for job in jobs {
+        let line = job.title_line();
+        let tokens = estimate_tokens(&line) + 1;
+        match batches.last_mut() {
+            Some(batch) if used + tokens <= max_tokens => batch.push(line),
+            _ => {
+                batches.push(vec![line]);
+                used = 0;
+            }
+        }
+        used += tokens;
+    }
+    batches
+}
+
+/// `filter_titles` answers checked against the jobs the model was shown.
+#[derive(Debug, Default)]
+pub struct TitleMatches {
+    /// The matching jobs as the source listed them, in listing order.
+    pub jobs: Vec<Job>,
+    /// Answers that name none of the listed jobs.
+    pub rejected: Vec<String>,
+}
+
+/// Maps the ids a model returned back to `listed` jobs. An answer must be one of their ids,
+/// or an echo of a whole `Job::title_line`; anything else is a hallucination and rejected.
+/// Only the listed jobs are ever returned, so nothing the model writes reaches the source.
+pub fn validate_title_matches(listed: Vec<Job>, returned_ids: &[String]) -> TitleMatches {
+    let mut matched = HashSet::new();
+    let mut rejected = Vec::new();
+    for answer in returned_ids {
+        let answer = answer.trim();
+        match listed
+            .iter()
+            .find(|job| job.id == answer || job.title_line() == answer)
+        {
+            Some(job) => {
+                matched.insert(job.id.clone());
+            }
+            None => rejected.push(answer.to_string()),
+        }
+    }
+    TitleMatches {
+        jobs: listed
+            .into_iter()
+            .filter(|job| matched.contains(&job.id))
+            .collect(),
+        rejected,
+    }
+}
 
 pub async fn process_company<J, L, S>(
     company: &Company,
@@ -12,31 +81,57 @@ pub async fn process_company<J, L, S>(
 ) -> ScannerResult<()>
 where
     J: JobSource + Send + Sync,
-    L: LLMProvider + Send + Sync,
+    L: LLMProvider + Send + Sync + ?Sized,
     S: Storage + Send + Sync,
 {
     let jobs = job_source.get_job_list(company).await?;
+    let today = Local::now().date_naive();
     let mut filtered_jobs = Vec::new();
 
     for job in jobs {
+        if let Some(max_age_days) = company.max_age_days
+            && job.is_stale(max_age_days, today)
+        {
+            continue;
+        }
+        if job.is_expired(today) {
+            continue;
+        }
         if storage.is_seen(&job.id).await? {
             continue;
         }
         filtered_jobs.push(job);
     }
 
-    let matching_job = llm.filter_titles(filtered_jobs, resume_text).await?;
+    let mut returned_ids = Vec::new();
+    for batch in title_batches(&filtered_jobs, TITLE_BATCH_TOKENS) {
+        returned_ids.extend(llm.filter_titles(&batch, resume_text).await?);
+    }
+    let matches = validate_title_matches(filtered_jobs, &returned_ids);
+    if !matches.rejected.is_empty() {
+        warn!(
+            "{} returned {} unknown job ids for {}: {:?}",
+            llm.model_name(),
+            matches.rejected.len(),
+            company.name,
+            matches.rejected
+        );
+    }
+    storage
+        .record_filter_stats(llm.model_name(), returned_ids.len(), matches.rejected.len())
+        .await?;
 
-    for job in matching_job {
-        let description = job_source.get_job_details(&job, &company.url).await?;
-        let analysis = llm.analyze_match(resume_text, &description).await?;
+    for job in matches.jobs {
+        let mut job = job_source.get_job_details(&job, company).await?;
+        job.description = job.description.as_deref().map(normalize_description);
+        let analysis = llm.analyze_match(resume_text, &job.prompt_text()).await?;
         if analysis.is_match {
             println!(
                 "✅ MATCH FOUND: {} at {} (Score: {})",
                 job.title, company.name, analysis.score
             );
             println!("Reasoning: {}", analysis.reasoning);
-            println!("Apply here: {}{}", company.url, job.external_path);
+            println!("Apply here: {}", job.apply_url(&company.url));
             println!("---");
 
             storage
@@ -53,3 +148,53 @@ where
 pub fn hi_processor() {
     println!("Hi from processor");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_title_batches_respect_the_token_budget() {
+        let jobs: Vec<Job> = (0..10)
+            .map(|i| Job {
+                id: format!("R{i}"),
+                title: "Backend Engineer".to_string(),
+                location: "Pune".to_string(),
+                ..Default::default()
+            })
+            .collect();
+        assert_eq!(jobs[0].title_line(), "R0: Backend Engineer (Pune)");
+
+        // Each line is 27 characters, 7 tokens plus one for the newline.
+        let batches = title_batches(&jobs, 20);
+        assert_eq!(batches.len(), 5);
+        assert!(batches.iter().all(|b| b.len() == 2));
+        assert_eq!(batches[4][1], "R9: Backend Engineer (Pune)");
+
+        assert_eq!(title_batches(&jobs, 1).len(), 10);
+        assert_eq!(title_batches(&jobs, 1_000).len(), 1);
+        assert!(title_batches(&[], 1_000).is_empty());
+    }
+
+    #[test]
+    fn test_validate_title_matches_rejects_unknown_ids() {
+        let listed: Vec<Job> = ["R1", "R2", "R3"]
+            .iter()
+            .map(|id| Job {
+                id: id.to_string(),
+                title: "Backend Engineer".to_string(),
+                external_path: format!("/job/{id}"),
+                ..Default::default()
+            })
+            .collect();
+        let returned = ["R3", " R1 ", "R3", "R2: Backend Engineer", "R9", "Backend Engineer"]
+            .map(String::from);
+        let matches = validate_title_matches(listed, &returned);
+        assert_eq!(
+            matches.jobs.iter().map(|j| j.id.as_str()).collect::<Vec<_>>(),
+            ["R1", "R2", "R3"]
+        );
+        assert_eq!(matches.jobs[2].external_path, "/job/R3");
+        assert_eq!(matches.rejected, ["R9", "Backend Engineer"]);
+    }
+}
@@ -5,5 +5,6 @@ use async_trait::async_trait;
 #[async_trait]
 pub trait JobSource {
     async fn get_job_list(&self, company: &Company) -> ScannerResult<Vec<Job>>;
-    async fn get_job_details(&self, job: &Job, url: &str) -> ScannerResult<String>;
+    /// Returns `job` completed with its description and any metadata the listing lacked.
+    async fn get_job_details(&self, job: &Job, company: &Company) -> ScannerResult<Job>;
 }
@@ -1,4 +1,4 @@
-use crate::core::model::Job;
+use crate::core::model::ExtractedPosting;
 use crate::error::ScannerResult;
 use async_trait::async_trait;
 
@@ -11,14 +11,20 @@ pub struct AnalysisResult {
 
 #[async_trait]
 pub trait LLMProvider {
+    /// Model the provider talks to, used to attribute bad answers.
+    fn model_name(&self) -> &str;
+    /// Picks the promising jobs from `title_lines`, each rendered by `Job::title_line`, and
+    /// returns their ids.
     async fn filter_titles(
         &self,
-        job_list: Vec<Job>,
+        title_lines: &[String],
         profile_summary: &str,
-    ) -> ScannerResult<Vec<Job>>;
+    ) -> ScannerResult<Vec<String>>;
     async fn analyze_match(
         &self,
         resume_text: &str,
         job_description: &str,
     ) -> ScannerResult<AnalysisResult>;
+    /// Reads the company, title, location and remote policy out of free text.
+    async fn extract_posting(&self, posting_text: &str) -> ScannerResult<ExtractedPosting>;
 }
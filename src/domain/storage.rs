@@ -7,4 +7,12 @@ pub trait Storage {
     async fn is_seen(&self, job_id: &str) -> ScannerResult<bool>;
     async fn mark_seen(&self, job_id: &str) -> ScannerResult<()>;
     async fn save_job(&self, job: &Job, score: u8, reasoning: String) -> ScannerResult<()>;
+    /// Adds to the running totals of ids a model returned from `filter_titles` and of those
+    /// that were not in the jobs it was shown.
+    async fn record_filter_stats(
+        &self,
+        model: &str,
+        returned_ids: usize,
+        rejected_ids: usize,
+    ) -> ScannerResult<()>;
 }
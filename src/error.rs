@@ -21,10 +21,25 @@ pub enum ScannerError {
     DatabaseError(#[from] rusqlite::Error),
 
     #[error("Openrouter Error: {0}")]
-    OpenRouterProviderError(#[from] OpenRouterError),
+    OpenRouterProviderError(Box<OpenRouterError>),
 
     #[error("Url parser error: {0}")]
-    UrlParseError(#[from] ParseError)
+    UrlParseError(#[from] ParseError),
+
+    #[error("Invalid job source URL `{0}`: {1}")]
+    SourceUrlError(String, String),
+
+    #[error("Facet error: {0}")]
+    FacetError(String),
+
+    #[error("XML parse error: {0}")]
+    XmlError(#[from] roxmltree::Error),
+}
+
+impl From<OpenRouterError> for ScannerError {
+    fn from(err: OpenRouterError) -> Self {
+        ScannerError::OpenRouterProviderError(Box::new(err))
+    }
 }
 
 pub type ScannerResult<T> = std::result::Result<T, ScannerError>;
@@ -1,42 +1,237 @@
+use async_trait::async_trait;
 use job_scanner::{
     adapters::{
-        llm::openrouter::OpenRouterProvider, sqlite_storage::SqliteStorage,
+        file_source::FileJobSource,
+        hacker_news_client::HackerNewsClient,
+        http::{HttpClient, HttpConfig},
+        llm::openrouter::OpenRouterProvider,
+        replay::ReplayServer,
+        sqlite_storage::SqliteStorage,
         workday_client::WorkdayClient,
     },
-    core::{model::Company, processor::process_company},
+    core::{
+        model::{Company, ExtractedPosting, ModelFilterStats, RemoteType, SourceKind},
+        processor::process_company,
+    },
+    domain::{
+        llm::{AnalysisResult, LLMProvider},
+        storage::Storage,
+    },
+    error::ScannerResult,
 };
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+
+/// Keeps backend titles and matches descriptions mentioning Rust.
+struct KeywordLlm;
+
+#[async_trait]
+impl LLMProvider for KeywordLlm {
+    fn model_name(&self) -> &str {
+        "keyword"
+    }
+
+    async fn filter_titles(&self, title_lines: &[String], _: &str) -> ScannerResult<Vec<String>> {
+        let mut ids: Vec<String> = title_lines
+            .iter()
+            .filter(|line| line.contains("Backend"))
+            .filter_map(|line| Some(line.split_once(": ")?.0.to_string()))
+            .collect();
+        // Made up by the model; must never reach the job source.
+        ids.push("acme-99".to_string());
+        Ok(ids)
+    }
+
+    async fn analyze_match(&self, _: &str, job_description: &str) -> ScannerResult<AnalysisResult> {
+        let is_match = job_description.contains("Rust");
+        Ok(AnalysisResult {
+            is_match,
+            reasoning: "keyword".to_string(),
+            score: if is_match { 90 } else { 10 },
+        })
+    }
+
+    async fn extract_posting(&self, _: &str) -> ScannerResult<ExtractedPosting> {
+        Ok(ExtractedPosting::default())
+    }
+}
+
+#[tokio::test]
+async fn process_company_replays_snapshot_offline() {
+    let company = Company {
+        name: "Acme".to_string(),
+        url: concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/jobs").to_string(),
+        source: SourceKind::File,
+        ..Default::default()
+    };
+    let storage = Arc::new(SqliteStorage::new(":memory:").unwrap());
+    process_company(
+        &company,
+        "Backend engineer, Rust",
+        Arc::new(FileJobSource::new()),
+        Arc::new(KeywordLlm),
+        storage.clone(),
+    )
+    .await
+    .unwrap();
+
+    // Both backend roles were analysed, the marketing one never passed the title filter.
+    assert!(storage.is_seen("acme-1").await.unwrap());
+    assert!(storage.is_seen("acme-2").await.unwrap());
+    assert!(!storage.is_seen("acme-3").await.unwrap());
+    assert!(!storage.is_seen("acme-99").await.unwrap());
+    assert_eq!(
+        storage.filter_stats().await.unwrap(),
+        [ModelFilterStats {
+            model: "keyword".to_string(),
+            returned_ids: 3,
+            rejected_ids: 1,
+        }]
+    );
+}
 
+/// Runs the whole pipeline against a Workday tenant and OpenRouter replayed from
+/// `tests/fixtures/replay`. Run with `JOB_SCANNER_RECORD=1` and `OPENAI_API_KEY` set to
+/// record fresh fixtures from the live services.
 #[tokio::test]
 async fn process_test() {
-    let athena_company = Company {
-        name: "athena health".to_string(),
-        url: "https://athenahealth.wd1.myworkdayjobs.com/wday/cxs/athenahealth/External"
-            .to_string(),
-        locations : None,
-        locationCountry : None
+    let fixtures = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/replay");
+    let workday = ReplayServer::start(
+        format!("{fixtures}/process_company/workday"),
+        "https://acme.wd1.myworkdayjobs.com",
+    )
+    .await
+    .unwrap();
+    let openrouter = ReplayServer::start(
+        format!("{fixtures}/process_company/openrouter"),
+        "https://openrouter.ai",
+    )
+    .await
+    .unwrap();
+
+    let acme_company = Company {
+        name: "Acme".to_string(),
+        url: "https://acme.wd1.myworkdayjobs.com/External".to_string(),
+        ..Default::default()
     };
     let resume_text = "A software backend engineer with 4 years of experince in Java and React";
-    let job_source = std::sync::Arc::new(WorkdayClient::new());
-    let api_key = std::env::var("OPENAI_API_KEY").unwrap();
-    let op = OpenRouterProvider::new(api_key, "qwen/qwen3-30b-a3b:free".to_string()).unwrap();
-    let l = std::sync::Arc::new(op);
-    match SqliteStorage::new("job_scanner.db") {
-        Ok(sqlite) => {
-            let storage = Arc::new(sqlite);
-            let res = process_company(
-                &athena_company,
-                &resume_text,
-                job_source.clone(),
-                l.clone(),
-                storage.clone(),
-            )
-            .await;
-            match res {
-                Err(err) => panic!("{:?}", err),
-                _ => (),
-            }
-        }
-        Err(_) => panic!("bad"),
+    let http = HttpClient::new(HttpConfig {
+        max_retries: 0,
+        requests_per_second: 0.0,
+        ..Default::default()
+    })
+    .unwrap()
+    .with_base_url(workday.url().clone());
+    let job_source = Arc::new(
+        WorkdayClient::new()
+            .with_page_size(2)
+            .with_http_client(http),
+    );
+    let api_key = std::env::var("OPENAI_API_KEY").unwrap_or_default();
+    let op = OpenRouterProvider::with_base_url(
+        api_key,
+        "qwen/qwen3-30b-a3b:free".to_string(),
+        openrouter.url().join("api/v1").unwrap().as_str(),
+    )
+    .unwrap();
+    let storage = Arc::new(SqliteStorage::new(":memory:").unwrap());
+    let res = process_company(
+        &acme_company,
+        resume_text,
+        job_source,
+        Arc::new(op),
+        storage.clone(),
+    )
+    .await;
+    if let Err(err) = res {
+        panic!("{:?}", err);
     }
+    assert!(storage.is_seen("Backend-Engineer_R101").await.unwrap());
+    assert!(!storage.is_seen("Frontend-Engineer_R103").await.unwrap());
+}
+
+/// Remembers the title lines it is asked to filter and reads prose postings as Globex's.
+#[derive(Default)]
+struct RecordingLlm {
+    title_lines: Mutex<Vec<String>>,
+}
+
+#[async_trait]
+impl LLMProvider for RecordingLlm {
+    fn model_name(&self) -> &str {
+        "recording"
+    }
+
+    async fn filter_titles(&self, title_lines: &[String], _: &str) -> ScannerResult<Vec<String>> {
+        self.title_lines
+            .lock()
+            .unwrap()
+            .extend(title_lines.iter().cloned());
+        Ok(Vec::new())
+    }
+
+    async fn analyze_match(&self, _: &str, _: &str) -> ScannerResult<AnalysisResult> {
+        unreachable!("no title passes the filter")
+    }
+
+    async fn extract_posting(&self, posting_text: &str) -> ScannerResult<ExtractedPosting> {
+        assert!(posting_text.starts_with("We're Globex"));
+        Ok(ExtractedPosting {
+            company: "Globex".to_string(),
+            title: "Data Platform Engineer".to_string(),
+            location: "Lisbon".to_string(),
+            remote_type: Some(RemoteType::Remote),
+        })
+    }
+}
+
+/// Hacker News comments are read before title filtering, so the filter ranks the
+/// extracted titles rather than the first line of each comment.
+#[tokio::test]
+async fn hacker_news_titles_are_extracted_before_filtering() {
+    let algolia = ReplayServer::start(
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/replay/process_company/hacker_news"
+        ),
+        "https://hn.algolia.com",
+    )
+    .await
+    .unwrap();
+    let http = HttpClient::new(HttpConfig {
+        max_retries: 0,
+        requests_per_second: 0.0,
+        ..Default::default()
+    })
+    .unwrap()
+    .with_base_url(algolia.url().clone());
+    let llm = Arc::new(RecordingLlm::default());
+    let job_source = Arc::new(
+        HackerNewsClient::new()
+            .with_http_client(http)
+            .with_llm(llm.clone()),
+    );
+    let company = Company {
+        name: "Hacker News".to_string(),
+        url: "https://news.ycombinator.com/item?id=40224213".to_string(),
+        source: SourceKind::HackerNews,
+        ..Default::default()
+    };
+    let storage = Arc::new(SqliteStorage::new(":memory:").unwrap());
+    process_company(
+        &company,
+        "Backend engineer, Rust",
+        job_source,
+        llm.clone(),
+        storage,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        *llm.title_lines.lock().unwrap(),
+        [
+            "hn-40224300: Backend Engineer (Berlin)",
+            "hn-40224301: Data Platform Engineer (Lisbon)",
+        ]
+    );
 }
@@ -1,4 +1,8 @@
-use job_scanner::{core::model::Company, domain::llm::AnalysisResult};
+use chrono::NaiveDate;
+use job_scanner::{
+    core::model::{Company, Compensation, Job},
+    domain::llm::AnalysisResult,
+};
 
 #[test]
 // check that ensures analysisResult values are correctlty stored.
@@ -8,7 +12,7 @@ fn roundtrip_analysis_result() {
         reasoning: "test reasoning".to_string(),
         score: 3u8,
     };
-    assert_eq!(analysis_result.is_match, true);
+    assert!(analysis_result.is_match);
     assert_eq!(analysis_result.reasoning, "test reasoning".to_string());
     assert_eq!(analysis_result.score, 3);
 }
@@ -18,7 +22,46 @@ fn roundtrip_company() {
     let company = Company {
         name: "test company".to_string(),
         url: "test api url".to_string(),
+        ..Default::default()
     };
     assert_eq!(company.name, "test company".to_string());
     assert_eq!(company.url, "test api url".to_string());
 }
+
+#[test]
+fn stale_job_is_detected_from_posting_date() {
+    let today = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+    let job = Job {
+        posted_at: NaiveDate::from_ymd_opt(2024, 2, 1),
+        ..Default::default()
+    };
+    assert!(job.is_stale(14, today));
+    assert!(!job.is_stale(29, today));
+    assert!(!Job::default().is_stale(0, today));
+}
+
+#[test]
+fn expired_job_is_detected_from_valid_through() {
+    let today = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+    let job = Job {
+        valid_through: NaiveDate::from_ymd_opt(2024, 2, 29),
+        ..Default::default()
+    };
+    assert!(job.is_expired(today));
+    assert!(!job.is_expired(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()));
+    assert!(!Job::default().is_expired(today));
+}
+
+#[test]
+fn compensation_describe_prefers_summary() {
+    let mut compensation = Compensation {
+        min: Some(81000.0),
+        max: Some(87000.0),
+        currency: Some("USD".to_string()),
+        interval: Some("YEAR".to_string()),
+        summary: None,
+    };
+    assert_eq!(compensation.describe(), "81000-87000 USD per YEAR");
+    compensation.summary = Some("$81K – $87K".to_string());
+    assert_eq!(compensation.describe(), "$81K – $87K");
+}
@@ -1,5 +1,6 @@
 use job_scanner::adapters::sqlite_storage::SqliteStorage;
-use job_scanner::core::model::Job;
+use chrono::NaiveDate;
+use job_scanner::core::model::{EmploymentType, Job, RemoteType};
 use job_scanner::domain::storage::Storage;
 
 #[tokio::test]
@@ -23,6 +24,7 @@ async fn test_save_and_retrieve_job() {
         location: "Remote".to_string(),
         description: None,
         external_path: "/test/path".to_string(),
+        ..Default::default()
     };
 
     storage
@@ -41,3 +43,46 @@ async fn test_save_and_retrieve_job() {
     assert_eq!(score, 85);
     assert_eq!(reasoning, "Good fit.");
 }
+
+#[tokio::test]
+async fn test_save_job_persists_metadata() {
+    let storage = SqliteStorage::new(":memory:").expect("Failed to create in-memory db");
+    let job = Job {
+        id: "saved_job_789".to_string(),
+        title: "Backend Engineer".to_string(),
+        company_name: "TestCo".to_string(),
+        location: "Pune".to_string(),
+        external_path: "/job/Pune/Backend-Engineer_R789".to_string(),
+        posted_at: NaiveDate::from_ymd_opt(2024, 5, 1),
+        requisition_id: Some("R789".to_string()),
+        employment_type: Some(EmploymentType::FullTime),
+        remote_type: Some(RemoteType::Hybrid),
+        country: Some("India".to_string()),
+        ..Default::default()
+    };
+
+    storage
+        .save_job(&job, 70, "Decent fit.".to_string())
+        .await
+        .unwrap();
+
+    let conn = storage.conn.lock().await;
+    let row: (String, String, String, String, Option<String>) = conn
+        .query_row(
+            "SELECT posted_at, requisition_id, employment_type, remote_type, hiring_organization FROM matched_jobs WHERE id = ?",
+            [&job.id],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?, row.get(4)?)),
+        )
+        .unwrap();
+
+    assert_eq!(
+        row,
+        (
+            "2024-05-01".to_string(),
+            "R789".to_string(),
+            "FullTime".to_string(),
+            "Hybrid".to_string(),
+            None
+        )
+    );
+}
@@ -1,4 +1,10 @@
-use job_scanner::adapters::workday_client::WorkdayJobPosting;
+use chrono::NaiveDate;
+use job_scanner::adapters::http::{HttpClient, HttpConfig};
+use job_scanner::adapters::replay::ReplayServer;
+use job_scanner::adapters::workday_client::{WorkdayClient, WorkdayJobPosting};
+use job_scanner::core::model::{Company, EmploymentType, Job, RemoteType};
+use job_scanner::domain::job_source::JobSource;
+use job_scanner::error::ScannerError;
 
 #[test]
 fn test_parse_workday_job_postings_successfully() {
@@ -23,3 +29,129 @@ fn test_parse_workday_job_postings_successfully() {
         "Senior Rust Developer"
     );
 }
+
+const TENANT: &str = "https://acme.wd1.myworkdayjobs.com/External";
+
+/// Starts a stand-in for the Workday tenant serving `tests/fixtures/replay/{fixtures}` and a
+/// client pointed at it. Set `JOB_SCANNER_RECORD=1` to refresh the fixtures from the tenant.
+async fn replay_client(fixtures: &str) -> (ReplayServer, WorkdayClient) {
+    let dir = format!(
+        "{}/tests/fixtures/replay/{fixtures}",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let server = ReplayServer::start(dir, "https://acme.wd1.myworkdayjobs.com")
+        .await
+        .unwrap();
+    let http = HttpClient::new(HttpConfig {
+        max_retries: 0,
+        requests_per_second: 0.0,
+        ..Default::default()
+    })
+    .unwrap()
+    .with_base_url(server.url().clone());
+    let client = WorkdayClient::new()
+        .with_page_size(2)
+        .with_http_client(http);
+    (server, client)
+}
+
+fn tenant() -> Company {
+    Company {
+        name: "Acme".to_string(),
+        url: TENANT.to_string(),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_replayed_listing_follows_pagination() {
+    let (_server, client) = replay_client("workday_pagination").await;
+    let company = tenant();
+    let jobs = client.get_job_list(&company).await.unwrap();
+    assert_eq!(
+        jobs.iter().map(|j| j.id.as_str()).collect::<Vec<_>>(),
+        [
+            "Backend-Engineer_R101",
+            "Data-Engineer_R102",
+            "Frontend-Engineer_R103"
+        ]
+    );
+    assert_eq!(jobs[2].remote_type, Some(RemoteType::Remote));
+
+    let job = client.get_job_details(&jobs[0], &company).await.unwrap();
+    assert_eq!(
+        job.description.as_deref(),
+        Some("<p>Build payment APIs in Java and Rust.</p>")
+    );
+    assert_eq!(job.posted_at, NaiveDate::from_ymd_opt(2024, 5, 1));
+    assert_eq!(job.employment_type, Some(EmploymentType::FullTime));
+    assert_eq!(job.country.as_deref(), Some("India"));
+    assert_eq!(
+        job.hiring_organization.as_deref(),
+        Some("Acme Technologies India")
+    );
+}
+
+#[tokio::test]
+async fn test_replayed_search_resolves_facet_names() {
+    let (_server, client) = replay_client("workday_facets").await;
+    let company = Company {
+        locations: Some("Pune".to_string()),
+        facets: [("timeType".to_string(), vec!["Full time".to_string()])]
+            .into_iter()
+            .collect(),
+        search_queries: vec!["rust".to_string()],
+        ..tenant()
+    };
+    let jobs = client.get_job_list(&company).await.unwrap();
+    assert_eq!(jobs.len(), 1);
+    assert_eq!(jobs[0].title, "Backend Engineer");
+
+    let unknown = Company {
+        locations: Some("Atlantis".to_string()),
+        ..company
+    };
+    assert!(matches!(
+        client.get_job_list(&unknown).await,
+        Err(ScannerError::FacetError(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_replayed_malformed_responses_are_errors() {
+    let (_server, client) = replay_client("workday_malformed").await;
+    let company = tenant();
+    // truncated JSON body
+    assert!(matches!(
+        client.get_job_list(&company).await,
+        Err(ScannerError::SerdeError(_))
+    ));
+    // server error once retries are exhausted
+    let searching = Company {
+        search_queries: vec!["rust".to_string()],
+        ..tenant()
+    };
+    assert!(matches!(
+        client.get_job_list(&searching).await,
+        Err(ScannerError::ApiError(e)) if e.status().is_some_and(|s| s.as_u16() == 500)
+    ));
+    // a detail payload of the wrong shape
+    let job = Job {
+        id: "Backend-Engineer_R101".to_string(),
+        external_path: "/job/Pune/Backend-Engineer_R101".to_string(),
+        ..Default::default()
+    };
+    assert!(matches!(
+        client.get_job_details(&job, &company).await,
+        Err(ScannerError::SerdeError(_))
+    ));
+    // nothing recorded for this posting
+    let missing = Job {
+        external_path: "/job/Pune/Gone_R999".to_string(),
+        ..job
+    };
+    assert!(matches!(
+        client.get_job_details(&missing, &company).await,
+        Err(ScannerError::ApiError(e)) if e.status().is_some_and(|s| s.as_u16() == 404)
+    ));
+}